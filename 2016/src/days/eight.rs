@@ -1,19 +1,14 @@
 use crate::utils::Part;
+use aoc_core::grid::Grid;
+use aoc_core::vector::Vector2D;
+use aoc_core::{trace, Answer};
 
-fn draw(screen: [[bool; 50]; 6]) {
-  println!("+--------------------------------------------------+");
-  for row in &screen {
-    print!("|");
-    for pixel in row.iter() {
-      print!("{}", match pixel { true => "#", false => " " });
-    }
-    println!("|");
-  }
-  println!("+--------------------------------------------------+");
+fn render(screen: &Grid<bool>) -> String {
+  format!("\n{}", screen.map(|pixel| match pixel { true => '#', false => ' ' }))
 }
 
-pub fn execute(input: String, part: &Part) {
-  let mut screen = [[false; 50]; 6];
+pub fn execute(input: String, part: &Part) -> Answer {
+  let mut screen = Grid::new(Vector2D::new(50, 6), false);
 
   for instruction in input.split("\n") {
     let mut parts = instruction.split(" ");
@@ -21,29 +16,29 @@ pub fn execute(input: String, part: &Part) {
     match verb {
       "rect" => {
         let sizes = parts.next().unwrap().split("x").map(|x| x.parse::<usize>().unwrap()).collect::<Vec<usize>>();
-        println!("Draw rect {}x{}.", sizes[0], sizes[1]);
+        trace!("Draw rect {}x{}.", sizes[0], sizes[1]);
 
-        for i in 0..sizes[1] {
-          for j in 0..sizes[0] {
-            screen[i][j] = true;
-          }
+        for y in 0..sizes[1] {
+          screen.row_mut(y)[..sizes[0]].fill(true);
         }
       },
       "rotate" => {
         let direction = parts.next().unwrap();
         let index = parts.next().unwrap().split("=").last().unwrap().parse::<usize>().unwrap();
         let offset = parts.last().unwrap().split("=").last().unwrap().parse::<usize>().unwrap();
-        println!("Move {} {} by {}.", direction, index, offset);
+        trace!("Move {} {} by {}.", direction, index, offset);
 
-        let mut pixel_array: Vec<bool> = Vec::new();
         match direction {
           "row" => {
-            for col in 0..50 { pixel_array.push(screen[index][col]) }
-            for col in 0..50 { screen[index][(col + offset) % 50] = pixel_array[col] }
+            let width = screen.width();
+            screen.row_mut(index).rotate_right(offset % width);
           },
           "column" => {
-            for row in 0..6 { pixel_array.push(screen[row][index]) }
-            for row in 0..6 { screen[(row + offset) % 6][index] = pixel_array[row] }
+            let mut column: Vec<bool> = screen.column(index).copied().collect();
+            column.rotate_right(offset % screen.height());
+            for (y, pixel) in column.into_iter().enumerate() {
+              screen[Vector2D::new(index, y)] = pixel;
+            }
           },
           _ => panic!("Unknown direction: {}", direction),
         }
@@ -51,19 +46,11 @@ pub fn execute(input: String, part: &Part) {
       _ => panic!("Unknown verb: {}", verb),
     }
 
-    if *part == Part::PartTwo {
-      draw(screen);
-    }
+    trace!("{}\n", render(&screen));
   }
 
-  if *part == Part::PartOne {
-      let mut lit_pixels = 0;
-      for row in &screen {
-        for pixel in row.iter() {
-          if *pixel { lit_pixels += 1 }
-        }
-      }
-      println!("Amount of lit pixels: {}", lit_pixels);
+  match part {
+    Part::PartOne => Answer::from(screen.iter().filter(|pixel| **pixel).count()),
+    Part::PartTwo => Answer::from(render(&screen)),
   }
-
 }
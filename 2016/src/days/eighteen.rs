@@ -1,6 +1,7 @@
 use crate::utils::Part;
+use aoc_core::Answer;
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut row = input.chars().map(|x| x == '^').collect::<Vec<bool>>();
   let mut traps_amount = 0;
   for _ in 0..match part { Part::PartOne => 40, Part::PartTwo => 400000 } {
@@ -12,10 +13,12 @@ pub fn execute(input: String, part: &Part) {
       let a = match i == 0 { true => false, false => *row.get(i-1).unwrap() };
       let b = *row.get(i).unwrap();
       let c = match row.get(i+1) { Some(t) => *t, None => false };
+      // The four trap rules, as listed in the puzzle
+      #[allow(clippy::nonminimal_bool)]
       new_row.push((a&&b&&!c) || (!a&&b&&c) || (a&&!b&&!c) || (!a&&!b&&c))
     }
     row = new_row;
   }
 
-  println!("Amount of traps: {}", traps_amount);
+  Answer::from(traps_amount)
 }
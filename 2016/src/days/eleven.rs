@@ -1,9 +1,8 @@
 use crate::utils::Part;
 use regex::Regex;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::collections::{BinaryHeap, HashSet};
-use std::cmp::Ordering;
-use chrono::Utc;
+use aoc_core::search::{a_star, SearchState};
+use aoc_core::Answer;
 
 #[derive(Copy, Clone)]
 enum Element {
@@ -40,7 +39,7 @@ enum Equipment {
   Microchip(Element), Generator(Element)
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 struct Floor {
   generators: [bool; 5],
   microships: [bool; 5],
@@ -114,17 +113,9 @@ impl Floor {
   }
 }
 
-impl PartialEq for Floor {
-    fn eq(&self, other: &Self) -> bool {
-        self.generators == other.generators && self.microships == other.microships
-    }
-}
-impl Eq for Floor {}
-
 #[derive(Copy, Clone)]
 struct Building {
   floors: [Floor; 4],
-  moves: usize,
   elevator: usize,
 }
 
@@ -160,7 +151,6 @@ impl Building {
     for next_floor in self.next_floors() {
       for i in 0..available_equipment.len() {
         let mut simple_neighbor = *self;
-        simple_neighbor.moves += 1;
         simple_neighbor.elevator = next_floor;
 
         let simple = available_equipment[i];
@@ -213,14 +203,6 @@ impl Building {
     self.floors[floor_number].install_equipment(equipment);
   }
 
-  fn cost(&self) -> usize {
-    let mut cost = self.moves * 5;
-    for i in 0..4 {
-      cost += self.floors[i].amount_equipment() * (3-i);
-    }
-    cost
-  }
-
   fn next_floors(&self) -> Vec<usize> {
     let mut next = Vec::new();
     if self.elevator < 3 {
@@ -232,43 +214,37 @@ impl Building {
     next
   }
 
-  fn has_same_floors(&self, other: &Self) -> bool {
-    self.floors == other.floors && self.elevator == other.elevator
-  }
-
-  fn is_solution(&self) -> bool {
-    self.floors[3].amount_equipment() == 10
-  }
-
   fn empty() -> Self {
     Building {
       floors: [Floor::empty(), Floor::empty(), Floor::empty(), Floor::empty()],
-      moves: 0,
       elevator: 0,
     }
   }
 }
 
-impl Ord for Building {
-  fn cmp(&self, other: &Self) -> Ordering {
-    other.cost().cmp(&self.cost())
+impl SearchState for Building {
+  type Key = ([Floor; 4], usize);
+
+  fn key(&self) -> Self::Key {
+    (self.floors, self.elevator)
   }
-}
 
-impl PartialOrd for Building {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+  fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
+    neighbours.extend(self.possible_moves().into_iter().map(|building| (building, 1)));
+  }
 
-impl PartialEq for Building {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost() == other.cost()
-    }
+  fn is_goal(&self) -> bool {
+    self.floors[3].amount_equipment() == 10
+  }
+
+  // The elevator lifts two items by one floor at most per move
+  fn heuristic(&self) -> usize {
+    let floors_left: usize = (0..4).map(|i| self.floors[i].amount_equipment() * (3-i)).sum();
+    floors_left.div_ceil(2)
+  }
 }
-impl Eq for Building {}
 
-fn part_one(input: String) {
+fn part_one(input: String) -> usize {
   let mut initial_building = Building::empty();
   let floor_contents = input.split("\n").collect::<Vec<&str>>();
 
@@ -290,34 +266,7 @@ fn part_one(input: String) {
     }
   }
 
-  let mut visited: Vec<Building> = Vec::new();
-  let mut solutions: BinaryHeap<Building> = BinaryHeap::new();
-  solutions.push(initial_building);
-
-  while {
-    let possibility = solutions.pop().expect("No possibilities left");
-    match possibility.is_solution() {
-      true => {
-        println!("Found Solution! Moves: {}", possibility.moves);
-        false
-      },
-      false => {
-        visited.push(possibility);
-        for next_possibility in possibility.possible_moves() {
-          if solutions.iter().find_map(|s| match s.has_same_floors(&next_possibility) {
-            true => Some(s),
-            false => None
-          }).is_none() && visited.iter().find_map(|s| match s.has_same_floors(&next_possibility) {
-            true => Some(s),
-            false => None
-          }).is_none(){
-            solutions.push(next_possibility);
-          }
-        }
-        true
-      },
-    }
-  } {}
+  a_star(initial_building).expect("No possibilities left").cost
 }
 
 const AMOUNT_MATERIALS: u64 = 7;
@@ -328,7 +277,6 @@ enum Operation { Plus, Minus }
 #[derive(Copy, Clone)]
 struct BitwiseBuilding {
   state: u64,
-  moves: u64,
 }
 
 impl BitwiseBuilding {
@@ -342,21 +290,7 @@ impl BitwiseBuilding {
   }
 
   fn floor(&self, f: u64) -> u64 {
-    self.state >> (AMOUNT_MATERIALS * 2) * f & 0b1111111_1111111
-  }
-
-  fn cost(&self) -> u64 {
-    let mut c = self.moves;
-    for i in 0..3 {
-      let mut f = self.floor(i);
-      let mut t = 0;
-      while f != 0 {
-        t += f & 1;
-        f >>= 1;
-      }
-      c += t * (3-i);
-    }
-    c
+    self.state >> ((AMOUNT_MATERIALS * 2) * f) & 0b1111111_1111111
   }
 
   fn is_valid(&self) -> bool {
@@ -383,7 +317,7 @@ impl BitwiseBuilding {
     self.state |= to_index;
   }
 
-  fn neighbors(&self) -> Vec<Self> {
+  fn possible_moves(&self) -> Vec<Self> {
     let current_floor = self.elevator();
     let floor = self.floor(current_floor);
     let mut available_equipment: Vec<u64> = Vec::new();
@@ -400,7 +334,6 @@ impl BitwiseBuilding {
 
       for i in 0..available_equipment.len() {
         let mut simple_neighbor = *self;
-        simple_neighbor.moves += 1;
         simple_neighbor.set_elevator(next_floor);
         simple_neighbor.move_item(current_floor, next_floor, available_equipment[i]);
         if simple_neighbor.is_valid() {
@@ -419,62 +352,45 @@ impl BitwiseBuilding {
 
     possibilities
   }
+}
 
+impl SearchState for BitwiseBuilding {
+  type Key = u64;
+
+  fn key(&self) -> Self::Key {
+    self.state
+  }
+
+  fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
+    neighbours.extend(self.possible_moves().into_iter().map(|building| (building, 1)));
+  }
+
+  #[allow(clippy::unusual_byte_groupings)]
   fn is_goal(&self) -> bool {
     // Elevator and all components on third floor
     self.state == 0b00000011_1111111_1111111_0000000_0000000_0000000_0000000_0000000_0000000
   }
-}
 
-impl Ord for BitwiseBuilding {
-  fn cmp(&self, other: &Self) -> Ordering {
-    other.cost().cmp(&self.cost())
+  // The elevator lifts two items by one floor at most per move
+  fn heuristic(&self) -> usize {
+    let floors_left: u64 = (0..3).map(|i| self.floor(i).count_ones() as u64 * (3-i)).sum();
+    floors_left.div_ceil(2) as usize
   }
 }
 
-impl PartialOrd for BitwiseBuilding {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl PartialEq for BitwiseBuilding {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost() == other.cost()
-    }
-}
-impl Eq for BitwiseBuilding {}
-
-fn part_two() {
-  let mut building = BitwiseBuilding {
+#[allow(clippy::unusual_byte_groupings)]
+fn part_two() -> usize {
+  let building = BitwiseBuilding {
     //       Elevator F4.Gen  F4.Mic  F3.Gen  F3.Mic  F2.Gen  F2.Mic  F1.Gen  F1.Mic
     state: 0b00000000_0000000_0000000_0000011_0000011_0000000_0001100_1111100_1110000,
-    moves: 0,
   };
 
-  let mut possibilities: BinaryHeap<BitwiseBuilding> = BinaryHeap::new();
-  let mut visited: HashSet<u64> = HashSet::new();
-  visited.insert(building.state);
-
-  while !building.is_goal() {
-    for possibility in building.neighbors() {
-      if visited.insert(possibility.state) {
-        possibilities.push(possibility);
-      }
-    }
-
-    building = possibilities.pop().expect("No more possibilities");
-  }
-
-  println!("Found solution in {} moves.", building.moves);
+  a_star(building).expect("No more possibilities").cost
 }
 
-pub fn execute(input: String, part: &Part) {
-  let start = Utc::now().time();
+pub fn execute(input: String, part: &Part) -> Answer {
   match part {
-    Part::PartOne => part_one(input),
-    Part::PartTwo => part_two(),
+    Part::PartOne => Answer::from(part_one(input)),
+    Part::PartTwo => Answer::from(part_two()),
   }
-  let end = Utc::now().time();
-  println!("Took {} seconds.", (end-start).num_seconds());
 }
@@ -1,5 +1,6 @@
 use crate::utils::Part;
 use regex::Regex;
+use aoc_core::Answer;
 
 struct Disk {
   offset: usize,
@@ -20,7 +21,7 @@ impl Disk {
   }
 
   fn is_aligned(&self, time: usize) -> bool {
-    (self.offset + self.position + time) % self.size == 0
+    (self.offset + self.position + time).is_multiple_of(self.size)
   }
 }
 
@@ -33,7 +34,7 @@ fn is_solution(time: usize, disks: &Vec<Disk>) -> bool {
   true
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut disks = Vec::<Disk>::new();
   for line in input.split("\n") {
     disks.push(Disk::from_string(line));
@@ -51,5 +52,5 @@ pub fn execute(input: String, part: &Part) {
   while !is_solution(i, &disks) {
     i += 1;
   }
-  println!("Must push button at second {}.", i);
+  Answer::from(i)
 }
\ No newline at end of file
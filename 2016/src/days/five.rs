@@ -1,5 +1,5 @@
 use crate::utils::Part;
-use md5;
+use aoc_core::{trace, Answer};
 
 fn is_password_filled(password: [Option<char>; 8]) -> bool {
   for character in &password {
@@ -10,7 +10,7 @@ fn is_password_filled(password: [Option<char>; 8]) -> bool {
   return true;
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   match part {
     Part::PartOne => {
       let mut password = String::new();
@@ -19,13 +19,13 @@ pub fn execute(input: String, part: &Part) {
         let digest = md5::compute(format!("{}{}", input, i));
         let hash: String = format!("{:?}", digest);
         if &hash[..5] == "00000" {
-          println!("Found match : Hash({}{}) == {}", input, i, hash);
+          trace!("Found match : Hash({}{}) == {}", input, i, hash);
           password.push(hash.chars().nth(5).expect("The hash has less than 6 characters"));
         }
         i += 1;
       }
-    
-      println!("\nThe password is: {}", password);
+
+      Answer::from(password)
     },
     Part::PartTwo => {
       let mut password: [Option<char>; 8] = [None; 8];
@@ -34,21 +34,21 @@ pub fn execute(input: String, part: &Part) {
         let digest = md5::compute(format!("{}{}", input, i));
         let hash: String = format!("{:?}", digest);
         if &hash[..5] == "00000" {
-          println!("Found match : Hash({}{}) == {}", input, i, hash);
+          trace!("Found match : Hash({}{}) == {}", input, i, hash);
           let index = hash.chars().nth(5).unwrap_or('8').to_digit(8).unwrap_or(8);
           if index >= 8 {
-            println!("Invalid index value.");
+            trace!("Invalid index value.");
           } else if password[index as usize].is_some() {
-            println!("Index already filled.");
+            trace!("Index already filled.");
           } else {
             password[index as usize] = hash.chars().nth(6);
           }
         }
         i += 1;
       }
-    
+
       let password_reduce = password.iter().map(|c| c.expect("Invalid password character"));
-      println!("\nThe password is: {}", password_reduce.collect::<String>());
+      Answer::from(password_reduce.collect::<String>())
     },
-  };
+  }
 }
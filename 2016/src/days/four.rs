@@ -1,4 +1,5 @@
 use crate::utils::Part;
+use aoc_core::{trace, Answer};
 use std::collections::{HashMap, BinaryHeap};
 use std::cmp::{Ord, Ordering, };
 use std::char;
@@ -37,21 +38,21 @@ impl PartialEq for KeyValuePair {
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut sum_ids = 0;
   let mut valid_rooms: Vec<(&str, usize)> = Vec::new();
 
   for line in input.split("\n") {
-    println!("{}", line);
+    trace!("{}", line);
 
     let name_limit = line.rfind('-')
-      .expect(format!("Error: Encrypted room has the wrong format : {}", line).as_str());
+      .unwrap_or_else(|| panic!("Error: Encrypted room has the wrong format : {}", line));
     let room_name = &line[0..name_limit];
 
     let room_limit = line.rfind('[')
-      .expect(format!("Error: Encrypted room has the wrong format : {}", line).as_str());
+      .unwrap_or_else(|| panic!("Error: Encrypted room has the wrong format : {}", line));
     let room_number = line[name_limit+1..room_limit].parse::<usize>()
-      .expect(format!("Error: Wrong sector ID format : {}. Expected number.", &line[name_limit+1..room_limit]).as_str());
+      .unwrap_or_else(|_| panic!("Error: Wrong sector ID format : {}. Expected number.", &line[name_limit+1..room_limit]));
     
     let checksum = &line[room_limit+1..line.len()-1];
 
@@ -73,7 +74,7 @@ pub fn execute(input: String, part: &Part) {
     for _ in 0..5 {
       calculated_chacksum.push(ordered_heap.pop().unwrap().character);
     }
-    println!("Calculated Chacksum: {}", calculated_chacksum);
+    trace!("Calculated Chacksum: {}", calculated_chacksum);
 
     if calculated_chacksum.eq(checksum) {
       sum_ids += room_number;
@@ -82,11 +83,11 @@ pub fn execute(input: String, part: &Part) {
   }
   
   match part {
-    Part::PartOne => println!("\nSum of valid room IDs: {}", sum_ids),
+    Part::PartOne => Answer::from(sum_ids),
     Part::PartTwo => {
-      println!("\nStarting room names validation");
+      trace!("\nStarting room names validation");
       for (room_name, room_number) in valid_rooms {
-        println!("Room: {} - {}", room_number, room_name);
+        trace!("Room: {} - {}", room_number, room_name);
         let mut real_room_name = String::new();
         for character in room_name.chars() {
           let mut next_character = character;
@@ -95,18 +96,18 @@ pub fn execute(input: String, part: &Part) {
               '-' => '-',
               'z' => 'a',
               _ => char::from_u32((next_character as u32) + 1)
-                .expect(format!("Invalid character '{}' in room name.", next_character).as_str()),
+                .unwrap_or_else(|| panic!("Invalid character '{}' in room name.", next_character)),
             };
           }
           real_room_name.push(next_character);
         }
-        println!("Real: {}", real_room_name);
+        trace!("Real: {}", real_room_name);
 
         if real_room_name.contains("north") {
-          println!("Found North Pole objects: {}", room_number);
-          return;
+          return Answer::from(room_number);
         }
       }
+      panic!("No room stores the North Pole objects")
     },
   }
 }
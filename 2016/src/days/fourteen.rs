@@ -1,8 +1,8 @@
 use crate::utils::Part;
 use std::collections::{BTreeMap, HashSet};
-use md5;
+use aoc_core::Answer;
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut keys: HashSet<usize> = HashSet::new();
   let mut potentials: BTreeMap<usize, char> = BTreeMap::new();
 
@@ -13,7 +13,7 @@ pub fn execute(input: String, part: &Part) {
     
     if *part == Part::PartTwo {
       for _ in 0..2016 {
-        digest = md5::compute(format!("{}", hash));
+        digest = md5::compute(&hash);
         hash = format!("{:x}", digest);
       }
     }
@@ -45,5 +45,5 @@ pub fn execute(input: String, part: &Part) {
       last = *key;
     }
   }
-  println!("Found the {}-th key. Last key index: {}", keys.len(), last);
+  Answer::from(last)
 }
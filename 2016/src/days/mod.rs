@@ -1,25 +1,73 @@
-pub mod one;
-pub mod two;
-pub mod three;
-pub mod four;
-pub mod five;
-pub mod six;
-pub mod seven;
-pub mod eight;
-pub mod nine;
-pub mod ten;
-pub mod eleven;
-pub mod twelve;
-pub mod thriteen;
-pub mod fourteen;
-pub mod fifteen;
-pub mod sixteen;
-pub mod seventeen;
-pub mod eighteen;
-pub mod nineteen;
-pub mod twenty;
-pub mod twentyone;
-pub mod twentytwo;
-pub mod twentythree;
-pub mod twentyfour;
-pub mod twentyfive;
+mod one;
+mod two;
+mod three;
+mod four;
+mod five;
+mod six;
+mod seven;
+mod eight;
+mod nine;
+mod ten;
+mod eleven;
+mod twelve;
+mod thriteen;
+mod fourteen;
+mod fifteen;
+mod sixteen;
+mod seventeen;
+mod eighteen;
+mod nineteen;
+mod twenty;
+mod twentyone;
+mod twentytwo;
+mod twentythree;
+mod twentyfour;
+mod twentyfive;
+
+use aoc_core::Solutions;
+use crate::utils::Part;
+
+/// Registers a day whose `execute` solves both parts, on the input without its trailing newline.
+macro_rules! solved {
+  ($module:ident) => {
+    Some(Solutions {
+      part1: vec![("main", &|input: &[u8]| $module::execute(trimmed(input), &Part::PartOne).to_string())],
+      part2: vec![("main", &|input: &[u8]| $module::execute(trimmed(input), &Part::PartTwo).to_string())],
+    })
+  };
+}
+
+fn trimmed(input: &[u8]) -> String {
+  String::from(String::from_utf8_lossy(input).trim_end())
+}
+
+pub fn solutions(day: usize) -> Option<Solutions<'static>> {
+  match day {
+    1 => solved!(one),
+    2 => solved!(two),
+    3 => solved!(three),
+    4 => solved!(four),
+    5 => solved!(five),
+    6 => solved!(six),
+    7 => solved!(seven),
+    8 => solved!(eight),
+    9 => solved!(nine),
+    10 => solved!(ten),
+    11 => solved!(eleven),
+    12 => solved!(twelve),
+    13 => solved!(thriteen),
+    14 => solved!(fourteen),
+    15 => solved!(fifteen),
+    16 => solved!(sixteen),
+    17 => solved!(seventeen),
+    18 => solved!(eighteen),
+    19 => solved!(nineteen),
+    20 => solved!(twenty),
+    21 => solved!(twentyone),
+    22 => solved!(twentytwo),
+    23 => solved!(twentythree),
+    24 => solved!(twentyfour),
+    25 => solved!(twentyfive),
+    _ => None,
+  }
+}
@@ -1,15 +1,17 @@
 use crate::utils::Part;
 use regex::Regex;
+use aoc_core::Answer;
 
-fn decompress(input: &str, recursive: bool) -> String {
+// Only the length is needed, the version 2 format would decompress to several gigabytes.
+fn decompressed_length(input: &str, recursive: bool) -> usize {
   let re = Regex::new(r"\([0-9]+x[0-9]+\)").unwrap();
-  let mut output = String::new();
+  let mut length = 0;
   let mut position = 0;
 
   for marker in re.find_iter(input) {
     if marker.start() < position { continue }
 
-    output.push_str(&input[position..marker.start()]);
+    length += marker.start() - position;
 
     let tag = marker.as_str();
     let parts = String::from(tag)[1..tag.len()-1]
@@ -18,18 +20,16 @@ fn decompress(input: &str, recursive: bool) -> String {
       .collect::<Vec<usize>>();
 
     let raw_pattern = &input[marker.end()..marker.end()+parts[0]];
-    let pattern = match recursive {
-      false => String::from(raw_pattern),
-      true => decompress(raw_pattern, true),
+    let pattern_length = match recursive {
+      false => raw_pattern.len(),
+      true => decompressed_length(raw_pattern, true),
     };
-    for _ in 0..parts[1] { output.push_str(pattern.as_str()) }
+    length += pattern_length * parts[1];
     position = marker.end()+parts[0];
   }
-  output.push_str(&input[position..input.len()]);
-  output
+  length + input.len() - position
 }
 
-pub fn execute(input: String, part: &Part) {
-  let output = decompress(input.as_str(), *part == Part::PartTwo);
-  println!("Decompressed length:  {}", output.len());
+pub fn execute(input: String, part: &Part) -> Answer {
+  Answer::from(decompressed_length(input.as_str(), *part == Part::PartTwo))
 }
@@ -1,7 +1,8 @@
 use crate::utils::Part;
 use std::collections::{LinkedList, VecDeque};
+use aoc_core::Answer;
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let amount_elves = input.parse::<usize>().unwrap();
 
   match part {
@@ -17,22 +18,25 @@ pub fn execute(input: String, part: &Part) {
         elves_circle.push_back((elf, current + steal_amount));
       }
 
-      println!("The last elf is no. {}", elves_circle.pop_front().unwrap().0);
+      Answer::from(elves_circle.pop_front().unwrap().0)
     },
     Part::PartTwo => {
-      let mut elves_circle = VecDeque::<usize>::new();
-      for i in 0..amount_elves {
-        elves_circle.push_back(i+1);
-      }
-      
-      while elves_circle.len() > 1 {
-        elves_circle.remove(elves_circle.len() / 2);
-        let saved = elves_circle.pop_front().unwrap();
-        elves_circle.push_back(saved);
+      // The circle is split in two halves so that the elf across is always at the front of
+      // the second one, removing it from the middle of a single list was quadratic.
+      let mut first_half: VecDeque<usize> = (1..=amount_elves / 2).collect();
+      let mut second_half: VecDeque<usize> = (amount_elves / 2 + 1..=amount_elves).collect();
+
+      while first_half.len() + second_half.len() > 1 {
+        if first_half.len() > second_half.len() {
+          first_half.pop_back();
+        } else {
+          second_half.pop_front();
+        }
+        second_half.push_back(first_half.pop_front().unwrap());
+        first_half.push_back(second_half.pop_front().unwrap());
       }
-      
-      println!("The last elf is no. {}", elves_circle.pop_front().unwrap());
+
+      Answer::from(first_half.pop_front().or_else(|| second_half.pop_front()).unwrap())
     }
   }
-
 }
@@ -1,63 +1,44 @@
 use crate::utils::Part;
-use crate::lib::{CardinalPoint, Vector2D};
+use aoc_core::direction::Direction;
+use aoc_core::vector::Vector2D;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use aoc_core::Answer;
 
+// The 2016 walk, unused since the input of this day was replaced with an expense report.
+#[allow(dead_code)]
 struct Santa {
-  position: Vector2D,
-  facing: CardinalPoint,
-  history: Vec<Vector2D>,
+  position: Vector2D<isize>,
+  facing: Direction,
+  history: Vec<Vector2D<isize>>,
 }
 
+#[allow(dead_code)]
 impl Santa {
-  fn has_been(&self, compare: &Vector2D) -> bool {
-    for coord in &self.history {
-      if coord.x == compare.x && coord.y == compare.y {
-        return true
-      }
-    };
-    false
+  fn has_been(&self, compare: &Vector2D<isize>) -> bool {
+    self.history.contains(compare)
   }
 
-  fn rotate(&mut self, direction: &str) -> &CardinalPoint {
-    match direction {
-      "L" => {
-        self.facing = match self.facing {
-          CardinalPoint::NORTH => CardinalPoint::WEST,
-          CardinalPoint::WEST => CardinalPoint::SOUTH,
-          CardinalPoint::SOUTH => CardinalPoint::EAST,
-          CardinalPoint::EAST => CardinalPoint::NORTH,
-        };
-      },
-      "R" => {
-        self.facing = match self.facing {
-          CardinalPoint::NORTH => CardinalPoint::EAST,
-          CardinalPoint::WEST => CardinalPoint::NORTH,
-          CardinalPoint::SOUTH => CardinalPoint::WEST,
-          CardinalPoint::EAST => CardinalPoint::SOUTH,
-        };
-      },
+  fn rotate(&mut self, direction: &str) -> &Direction {
+    self.facing = match direction {
+      "L" => self.facing.turn_left(),
+      "R" => self.facing.turn_right(),
       _ => panic!("Unknown turn direction: {}", direction),
     };
     &self.facing
   }
 
-  fn walk(&mut self, distance: isize, stop_on_visited: bool) -> Result<&Vector2D, &Vector2D> {
+  fn walk(&mut self, distance: isize, stop_on_visited: bool) -> Result<&Vector2D<isize>, &Vector2D<isize>> {
     for _ in 0..distance {
-      match self.facing {
-        CardinalPoint::NORTH => self.position.y -= 1,
-        CardinalPoint::WEST => self.position.x -= 1,
-        CardinalPoint::SOUTH => self.position.y += 1,
-        CardinalPoint::EAST => self.position.x += 1,
-      };
+      self.position += self.facing.step();
       if stop_on_visited && self.has_been(&self.position){
         return Err(&self.position)
       }
-      self.history.push(self.position.clone());
+      self.history.push(self.position);
     }
     Ok(&self.position)
   }
   
-  fn follow_instruction(&mut self, parts: (&str, &str), stop_on_visited: bool) -> Result<&Vector2D, &Vector2D> {
+  fn follow_instruction(&mut self, parts: (&str, &str), stop_on_visited: bool) -> Result<&Vector2D<isize>, &Vector2D<isize>> {
     self.rotate(parts.0);
     self.walk(parts.1.parse::<isize>().unwrap(), stop_on_visited)
   }
@@ -65,21 +46,21 @@ impl Santa {
 
 impl Display for Santa {
   fn fmt(&self, f: &mut Formatter) -> FmtResult {
-    write!(f, "Santa( position: {}, facing: {} )", self.position, self.facing)
+    write!(f, "Santa( position: {}, facing: {:?} )", self.position, self.facing)
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, _part: &Part) -> Answer {
   let numbers: Vec<&str> = input.split("\n").collect();
   for i in 0..numbers.len() {
     for j in 0..numbers.len() {
       for k in 0..numbers.len() {
       if i == j || i == k  || j == k { continue }
         if numbers.get(i).unwrap().parse::<u32>().unwrap() + numbers.get(j).unwrap().parse::<u32>().unwrap() + numbers.get(k).unwrap().parse::<u32>().unwrap() == 2020 {
-          println!("{}", numbers.get(i).unwrap().parse::<u32>().unwrap() * numbers.get(k).unwrap().parse::<u32>().unwrap() * numbers.get(j).unwrap().parse::<u32>().unwrap());
+          return Answer::from(numbers.get(i).unwrap().parse::<u32>().unwrap() * numbers.get(k).unwrap().parse::<u32>().unwrap() * numbers.get(j).unwrap().parse::<u32>().unwrap());
         }
       }
     }
   }
-
+  panic!("No three entries sum to 2020")
 }
\ No newline at end of file
@@ -1,13 +1,14 @@
 use crate::utils::Part;
 use regex::Regex;
+use aoc_core::{trace, Answer};
 
 fn contains_abba(section: &str) -> bool {
   for i in 0..section.len() - 3 {
     let segment = &section[i..i+4];
     if
-      segment.chars().nth(0).unwrap() == segment.chars().nth(3).unwrap() &&
+      segment.chars().next().unwrap() == segment.chars().nth(3).unwrap() &&
       segment.chars().nth(1).unwrap() == segment.chars().nth(2).unwrap() &&
-      segment.chars().nth(0).unwrap() != segment.chars().nth(1).unwrap()
+      segment.chars().next().unwrap() != segment.chars().nth(1).unwrap()
     {
       return true
     }
@@ -20,8 +21,8 @@ fn get_all_aba(section: &str) -> Vec<String> {
   for i in 0..section.len() - 2 {
     let segment = &section[i..i+3];
     if
-      segment.chars().nth(0).unwrap() == segment.chars().nth(2).unwrap() &&
-      segment.chars().nth(0).unwrap() != segment.chars().nth(1).unwrap()
+      segment.chars().next().unwrap() == segment.chars().nth(2).unwrap() &&
+      segment.chars().next().unwrap() != segment.chars().nth(1).unwrap()
     {
       aba_list.push(String::from(segment));
     }
@@ -78,17 +79,17 @@ fn supports_ssl(ip: &str) -> bool {
   false
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut valid_ips_count = 0;
   for ip in input.split("\n") {
     if match part {
       Part::PartOne => valid_ip(ip),
       Part::PartTwo => supports_ssl(ip),
     } {
-      println!("Valid IP: \n{}", ip);
+      trace!("Valid IP: \n{}", ip);
       valid_ips_count += 1;
     }
   }
 
-  println!("\nAmount of valid IPs: \n{}", valid_ips_count);
+  Answer::from(valid_ips_count)
 }
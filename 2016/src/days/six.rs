@@ -1,7 +1,8 @@
 use crate::utils::Part;
 use std::char;
+use aoc_core::Answer;
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut amounts: [[usize; 26]; 8] = [[0; 26]; 8];
   for word in input.split("\n") {
     for i in 0..word.len() {
@@ -28,5 +29,5 @@ pub fn execute(input: String, part: &Part) {
     char::from_u32((best_index + 97) as u32).unwrap_or('#')
   }).collect::<String>();
 
-  println!("{}", secret);
+  Answer::from(secret)
 }
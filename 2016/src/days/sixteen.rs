@@ -1,8 +1,9 @@
 use crate::utils::Part;
+use aoc_core::Answer;
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let disk_size = match part { Part::PartOne => 272, Part::PartTwo => 35651584 };
-  let mut data = String::from(input);
+  let mut data = input;
   while data.len() < disk_size {
     
     data = format!("{}0{}", data, data.chars().rev().map(|x| match x {
@@ -22,5 +23,5 @@ pub fn execute(input: String, part: &Part) {
     }
   }
 
-  println!("Checksum: {}", checksum);
+  Answer::from(checksum)
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use crate::utils::Part;
 use std::collections::HashMap;
 use regex::Regex;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use aoc_core::{trace, Answer};
 
 #[derive(Copy, Clone)]
 enum ContainerType {
@@ -63,7 +64,7 @@ impl Robot {
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut robots: HashMap<usize, Robot> = HashMap::new();
   let mut initial_state: Vec<(usize, usize)> = Vec::new();
   let re = Regex::new(r"(bot|output|value) [0-9]+").unwrap();
@@ -104,6 +105,7 @@ pub fn execute(input: String, part: &Part) {
   }
 
   let mut outputs: HashMap<usize, usize> = HashMap::new();
+  let mut comparing_robot = None;
 
   while {
     let potential_container = robots.iter_mut().find_map(|(_, b)| match b.is_giving() {
@@ -112,11 +114,11 @@ pub fn execute(input: String, part: &Part) {
     });
     match potential_container {
       Some(robot) => {
-        println!("Robot({}) can give!", robot.number);
+        trace!("Robot({}) can give!", robot.number);
         match robot.give(part) {
           Some(gifts) => {
             for (to, value) in gifts.iter() {
-              println!("Giving {} to {}", value, to);
+              trace!("Giving {} to {}", value, to);
               match to {
                 ContainerType::Robot(n) => {
                   let giving_to = robots.get_mut(n).unwrap();
@@ -130,7 +132,8 @@ pub fn execute(input: String, part: &Part) {
             true
           },
           None => {
-            println!("Found the robot comparing values 17 and 61!");
+            trace!("Found the robot comparing values 17 and 61!");
+            comparing_robot = Some(robot.number);
             false
           },
         }
@@ -139,17 +142,17 @@ pub fn execute(input: String, part: &Part) {
     }
   } {}
 
-  if *part == Part::PartTwo {
-    println!();
-    for (number, item) in &outputs {
-      println!("Output {} contains items: {}", number, item);
-    }
-
-    let o0 = outputs.get(&0).unwrap();
-    let o1 = outputs.get(&1).unwrap();
-    let o2 = outputs.get(&2).unwrap();
-    println!("\nOutput(0) * Output(1) * Output(2) = {}", o0 * o1 * o2);
+  match part {
+    Part::PartOne => Answer::from(comparing_robot.expect("No robot compares values 17 and 61")),
+    Part::PartTwo => {
+      for (number, item) in &outputs {
+        trace!("Output {} contains items: {}", number, item);
+      }
+
+      let o0 = outputs.get(&0).unwrap();
+      let o1 = outputs.get(&1).unwrap();
+      let o2 = outputs.get(&2).unwrap();
+      Answer::from(o0 * o1 * o2)
+    },
   }
-
-
 }
@@ -1,4 +1,5 @@
 use crate::utils::Part;
+use aoc_core::{trace, Answer};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 struct Triangle {
@@ -7,9 +8,9 @@ struct Triangle {
 
 impl Triangle {
   fn from_strings(a: &str, b: &str, c: &str) -> Triangle {
-    let side1 = a.trim().parse::<usize>().expect(format!("The string {} is not a valid side length. Expecting unsigned int.", a).as_str());
-    let side2 = b.trim().parse::<usize>().expect(format!("The string {} is not a valid side length. Expecting unsigned int.", b).as_str());
-    let side3 = c.trim().parse::<usize>().expect(format!("The string {} is not a valid side length. Expecting unsigned int.", c).as_str());
+    let side1 = a.trim().parse::<usize>().unwrap_or_else(|_| panic!("The string {} is not a valid side length. Expecting unsigned int.", a));
+    let side2 = b.trim().parse::<usize>().unwrap_or_else(|_| panic!("The string {} is not a valid side length. Expecting unsigned int.", b));
+    let side3 = c.trim().parse::<usize>().unwrap_or_else(|_| panic!("The string {} is not a valid side length. Expecting unsigned int.", c));
     Triangle { sides: (side1, side2, side3)}
   }
 
@@ -26,7 +27,7 @@ impl Display for Triangle {
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let lines = input.split("\n");
   let mut valid_amount = 0;
 
@@ -34,13 +35,13 @@ pub fn execute(input: String, part: &Part) {
     Part::PartOne => {
       for line in lines {
         let triangle = Triangle::from_strings(&line[0..5], &line[5..10], &line[10..15]);
-        println!("New Triangle: {}", triangle);
+        trace!("New Triangle: {}", triangle);
         match triangle.is_valid() {
           true => {
-            println!("Valid");
+            trace!("Valid");
             valid_amount += 1;
           },
-          false => println!("Error: This triangle is not valid!"),
+          false => trace!("Error: This triangle is not valid!"),
         }
       }
     },
@@ -51,14 +52,14 @@ pub fn execute(input: String, part: &Part) {
 
         if buffer.len() == 3 {
           for i in 0..3 {
-            let triangle = Triangle::from_strings(&buffer[0][5*i+0..5*i+5], &buffer[1][5*i+0..5*i+5], &buffer[2][5*i+0..5*i+5]);
-            println!("New Triangle: {}", triangle);
+            let triangle = Triangle::from_strings(&buffer[0][5*i..5*i+5], &buffer[1][5*i..5*i+5], &buffer[2][5*i..5*i+5]);
+            trace!("New Triangle: {}", triangle);
             match triangle.is_valid() {
               true => {
-                println!("Valid");
+                trace!("Valid");
                 valid_amount += 1;
               },
-              false => println!("Error: This triangle is not valid!"),
+              false => trace!("Error: This triangle is not valid!"),
             }
           }
           buffer.clear();
@@ -67,5 +68,5 @@ pub fn execute(input: String, part: &Part) {
     },
   }
 
-  println!("\nAmount of valid triangles: {}", valid_amount);
+  Answer::from(valid_amount)
 }
@@ -1,127 +1,91 @@
 use crate::utils::Part;
-use std::collections::{BinaryHeap, HashSet};
-use std::cmp::Ordering;
+use std::collections::HashSet;
+use aoc_core::search::{a_star, Order, Queue, Search, SearchState};
+use aoc_core::{trace, Answer};
+
+const GOAL: (i64, i64) = (31, 39);
+const MAX_MOVES: usize = 50;
 
 #[derive(Copy, Clone)]
-struct Path {
-  x: i64,
-  y: i64,
-  moves: i64,
-  x_goal: i64,
-  y_goal: i64,
+struct Office {
+  secret: i64,
 }
 
-impl Path {
-  fn unique_key(&self) -> u64 {
-    (self.x as u64) << 32 | (self.y as u64)
+impl Office {
+  fn is_valid(&self, x: i64, y: i64) -> bool {
+    let t = x*x + 3*x + 2*x*y + y + y*y + self.secret;
+    t.count_ones().is_multiple_of(2)
   }
 
-  fn neighbors(&self) -> Vec<Self> {
-    let mut possibilities = Vec::new();
-    for (x_offset, y_offset) in &[(0,1),(0,-1),(1,0),(-1,0)] {
-      if &self.x + x_offset < 0 || &self.y + y_offset < 0 { continue }
-      let mut neighbor = *self;
-      neighbor.x += x_offset;
-      neighbor.y += y_offset;
-      neighbor.moves += 1;
-      possibilities.push(neighbor);
+  // The walls around the way taken, drawn with `O`
+  fn draw(&self, path: &[Path]) -> String {
+    let width = path.iter().map(|p| p.x).max().unwrap_or(0) + 2;
+    let height = path.iter().map(|p| p.y).max().unwrap_or(0) + 2;
+    let mut drawing = String::new();
+    for y in 0..height {
+      for x in 0..width {
+        drawing.push(match (path.iter().any(|p| (p.x, p.y) == (x, y)), self.is_valid(x, y)) {
+          (true, _) => 'O',
+          (false, true) => '.',
+          (false, false) => '#',
+        });
+      }
+      drawing.push('\n');
     }
-    possibilities
-  }
-
-  fn cost(&self) -> i64 {
-    &self.moves + (&self.x_goal - &self.x).abs() + (&self.y_goal - &self.y).abs()
-  }
-
-  fn is_goal(&self) -> bool {
-    (&self.x_goal - &self.x).abs() + (&self.y_goal - &self.y).abs() == 0
+    drawing
   }
 }
 
-impl Ord for Path {
-  fn cmp(&self, other: &Self) -> Ordering {
-    other.cost().cmp(&self.cost())
-  }
-}
-
-impl PartialOrd for Path {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+#[derive(Copy, Clone)]
+struct Path {
+  office: Office,
+  x: i64,
+  y: i64,
+  moves: usize,
+  max_moves: Option<usize>,
 }
 
-impl PartialEq for Path {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost() == other.cost()
-    }
-}
-impl Eq for Path {}
+impl SearchState for Path {
+  type Key = (i64, i64);
 
-struct Office {
-  secret: i64,
-  known_positions: HashSet<u64>,
-  paths: BinaryHeap<Path>,
-}
+  fn key(&self) -> Self::Key {
+    (self.x, self.y)
+  }
 
-impl Office {
-  fn is_valid(&self, x: i64, y: i64) -> bool {
-    let mut t = x*x + 3*x + 2*x*y + y + y*y;
-    t += self.secret;
-    let mut c = 0;
-    while t > 0 {
-      c += t & 1;
-      t >>= 1;
-    };
-    c % 2 == 0
+  fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
+    if self.max_moves.is_some_and(|max_moves| self.moves >= max_moves) { return }
+    for (x_offset, y_offset) in &[(0,1),(0,-1),(1,0),(-1,0)] {
+      let (x, y) = (self.x + x_offset, self.y + y_offset);
+      if x < 0 || y < 0 || !self.office.is_valid(x, y) { continue }
+      neighbours.push((Path { x, y, moves: self.moves + 1, ..*self }, 1));
+    }
   }
 
-  fn solve(&mut self, initial_position: &Path, part: &Part) -> i64 {
-    self.known_positions.insert(initial_position.unique_key());
-    
-    let mut position = *initial_position;
-    while match part {Part::PartOne => !position.is_goal(), Part::PartTwo => true} {
-      for neighbor in position.neighbors() {
-        if self.is_valid(neighbor.x, neighbor.y) {
-          if *part == Part::PartOne || neighbor.moves <= 50 {
-            if self.known_positions.insert(neighbor.unique_key()) {
-              self.paths.push(neighbor);
-            }
-          }
-        }
-      }
+  fn is_goal(&self) -> bool {
+    (self.x, self.y) == GOAL
+  }
 
-      let potential = self.paths.pop();
-      match potential {
-        Some(p) => position = p,
-        None => {
-          match part {
-            Part::PartOne => panic!("No more available paths"),
-            Part::PartTwo =>  return self.known_positions.len() as i64,
-          }
-        },
-      };
-    };
-    position.moves
+  fn heuristic(&self) -> usize {
+    ((GOAL.0 - self.x).abs() + (GOAL.1 - self.y).abs()) as usize
   }
 }
 
-pub fn execute(input: String, part: &Part) {
-  let boss_favorite_number = input.parse::<i64>().unwrap();
-  let mut office = Office {
-    secret: boss_favorite_number,
-    known_positions: HashSet::new(),
-    paths: BinaryHeap::new(),
-  };
-  let solution = office.solve(&Path {
-    x: 1,
-    y: 1,
-    moves: 0,
-    x_goal: 31,
-    y_goal: 39,
-  }, part);
+pub fn execute(input: String, part: &Part) -> Answer {
+  let office = Office { secret: input.parse::<i64>().unwrap() };
+  let start = Path { office, x: 1, y: 1, moves: 0, max_moves: None };
 
   match part {
-    Part::PartOne => println!("Puzzle can be solved in {} moves.", solution),
-    Part::PartTwo => println!("{} available moves.", solution),
-  };
+    Part::PartOne => {
+      let found = a_star(start).expect("No more available paths");
+      trace!("{}{}", office.draw(&found.path), found.stats);
+      Answer::from(found.cost)
+    },
+    Part::PartTwo => {
+      // The goal is out of reach within the moves, the search ends once every position is visited
+      let mut search = Search::new(Order::Cost, Queue::new(), HashSet::new());
+      search.run(Path { max_moves: Some(MAX_MOVES), ..start });
+      trace!("{}", search.stats());
+      Answer::from(search.visited().len())
+    },
+  }
 }
@@ -1,4 +1,5 @@
 use crate::utils::Part;
+use aoc_core::Answer;
 
 enum Value {
   Integer(isize), Register(usize)
@@ -24,8 +25,8 @@ impl Instruction {
 
   fn from_string(s: &str) -> Self {
     let mut parts = s.split(" ");
-    let verb = parts.next().expect(format!("Invalid instruction (No verb): {}", s).as_str());
-    let first_arg = parts.next().expect(format!("Invalid instruction (No args): {}", s).as_str());
+    let verb = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No verb): {}", s));
+    let first_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No args): {}", s));
     let second_arg = parts.next();
     match verb {
       "cpy" => {
@@ -34,7 +35,7 @@ impl Instruction {
             Ok(v) => Value::Integer(v),
             Err(_) => Value::Register(Self::register_index(first_arg)),
           },
-          Self::register_index(second_arg.expect(format!("Invalid instruction (No second arg): {}", s).as_str())),
+          Self::register_index(second_arg.unwrap_or_else(|| panic!("Invalid instruction (No second arg): {}", s))),
         )
       }
       "jnz" => {
@@ -44,9 +45,9 @@ impl Instruction {
             Err(_) => Value::Register(Self::register_index(first_arg)),
           },
           second_arg
-            .expect(format!("Invalid instruction (No second arg): {}", s).as_str())
+            .unwrap_or_else(|| panic!("Invalid instruction (No second arg): {}", s))
             .parse::<isize>()
-            .expect(format!("Invalid jump amount. Expected integer: {}", s).as_str()),
+            .unwrap_or_else(|_| panic!("Invalid jump amount. Expected integer: {}", s)),
         )
       }
       "inc" => Self::Increment(Self::register_index(first_arg)),
@@ -75,7 +76,7 @@ impl Computer {
       },
       Instruction::JumpIfNotZero(value, amount) => {
         let condition = match value {
-          Value::Integer(i) => &i,
+          Value::Integer(i) => i,
           Value::Register(r) => &self.registers[*r],
         };
         match condition {
@@ -99,14 +100,14 @@ impl Computer {
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut instructions: Vec<Instruction> = Vec::new();
   for line in input.split("\n") {
     instructions.push(Instruction::from_string(line))
   }
 
   let mut computer = Computer {
-    instructions: instructions,
+    instructions,
     registers: [0, 0, match part { Part::PartOne => 0, Part::PartTwo => 1 }, 0],
     offset: 0,
   };
@@ -115,5 +116,5 @@ pub fn execute(input: String, part: &Part) {
     computer.compute();
   }
 
-  println!("Register A : {}", computer.registers[Instruction::register_index("a")]);
+  Answer::from(computer.registers[Instruction::register_index("a")])
 }
@@ -1,4 +1,5 @@
 use crate::utils::Part;
+use aoc_core::{trace, Answer};
 
 
 #[derive(Copy, Clone)]
@@ -45,8 +46,8 @@ impl Instruction {
 
   fn from_string(s: &str) -> Self {
     let mut parts = s.split(" ");
-    let verb = parts.next().expect(format!("Invalid instruction (No verb): {}", s).as_str());
-    let first_arg = parts.next().expect(format!("Invalid instruction (No args): {}", s).as_str());
+    let verb = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No verb): {}", s));
+    let first_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No args): {}", s));
     let first_value = match first_arg.parse::<isize>() {
       Ok(v) => Value::Integer(v),
       Err(_) => Value::Register(Self::register_index(first_arg)),
@@ -54,7 +55,7 @@ impl Instruction {
 
     match verb {
       "cpy" => {
-        let second_arg = parts.next().expect(format!("Invalid instruction (No second arg): {}", s).as_str());
+        let second_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No second arg): {}", s));
         Self::CopyTo(
           first_value,
           match second_arg.parse::<isize>() {
@@ -64,7 +65,7 @@ impl Instruction {
         )
       }
       "jnz" => {
-        let second_arg = parts.next().expect(format!("Invalid instruction (No second arg): {}", s).as_str());
+        let second_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No second arg): {}", s));
         Self::JumpIfNotZero(
           first_value,
           match second_arg.parse::<isize>() {
@@ -306,11 +307,11 @@ impl Computer {
   }
   
   #[allow(dead_code)]
-  fn print_instructions(&self) {
+  fn trace_instructions(&self) {
     for i in 0..self.instructions.len() {
       let instruction = self.instructions.get(i).unwrap();
       match instruction {
-        Instruction::CopyTo(a, b) => println!(
+        Instruction::CopyTo(a, b) => trace!(
           "{} cpy {} {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -322,7 +323,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::JumpIfNotZero(a, b) => println!(
+        Instruction::JumpIfNotZero(a, b) => trace!(
           "{} jnz {} {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -334,7 +335,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Increment(a) => println!(
+        Instruction::Increment(a) => trace!(
           "{} inc {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -342,7 +343,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Decrement(a) => println!(
+        Instruction::Decrement(a) => trace!(
           "{} dec {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -350,7 +351,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Toggle(a) => println!(
+        Instruction::Toggle(a) => trace!(
           "{} tgl {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -358,7 +359,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Out(a) => println!(
+        Instruction::Out(a) => trace!(
           "{} out {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -375,18 +376,18 @@ impl Computer {
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, _part: &Part) -> Answer {
   let mut instructions: Vec<Instruction> = Vec::new();
   for line in input.split("\n") {
     instructions.push(Instruction::from_string(line))
   }
 
-  let mut foundValid = false;
+  let mut found_valid = false;
   let mut i = 1;
 
-  while !foundValid {
+  while !found_valid {
     let mut computer = Computer {
-      instructions: instructions.iter().map(|i| *i).collect(),
+      instructions: instructions.to_vec(),
       registers: [i, 0, 0, 0],
       offset: 0,
       last_print: None,
@@ -400,12 +401,12 @@ pub fn execute(input: String, part: &Part) {
     }
 
     if computer.valid && !computer.is_finished() {
-      foundValid = true;
+      found_valid = true;
     } else {
       i += 1;
     }
 
   }
 
-  println!("First Found: {}", i);
+  Answer::from(i)
 }
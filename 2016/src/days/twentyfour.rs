@@ -1,7 +1,7 @@
 use crate::utils::Part;
-use std::collections::{HashMap, BinaryHeap, HashSet};
-use std::cmp::Ordering;
-use std::usize;
+use std::collections::HashMap;
+use aoc_core::search::{a_star, SearchState};
+use aoc_core::Answer;
 
 #[derive(Copy, Clone)]
 struct Position {
@@ -26,49 +26,36 @@ impl Position {
 }
 
 #[derive(Copy, Clone)]
-struct Path {
+struct Path<'a> {
+  pipes: &'a Vec<Vec<bool>>,
   position: Position,
   goal: Position,
-  moves: usize,
 }
 
-impl Path {
-  fn neighbors(&self) -> Vec<Self> {
-    self.position.neighbors().iter().map(|p| Path { position: *p, moves: self.moves + 1, goal: self.goal}).collect()
-  }
+impl SearchState for Path<'_> {
+  type Key = u16;
 
-  fn cost(&self) -> usize {
-    self.moves + ((self.position.x as isize - self.goal.x as isize).abs() + (self.position.y as isize - self.goal.y as isize).abs()) as usize
+  fn key(&self) -> Self::Key {
+    self.position.key()
   }
 
-  fn key(&self) -> u16 {
-    self.position.key()
+  fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
+    for position in self.position.neighbors() {
+      if self.pipes[position.x][position.y] {
+        neighbours.push((Path { position, ..*self }, 1));
+      }
+    }
   }
 
-  fn is_solution(&self) -> bool {
+  fn is_goal(&self) -> bool {
     self.position.x == self.goal.x && self.position.y == self.goal.y
   }
-}
 
-impl Ord for Path {
-  fn cmp(&self, other: &Self) -> Ordering {
-    other.cost().cmp(&self.cost())
+  fn heuristic(&self) -> usize {
+    self.position.x.abs_diff(self.goal.x) + self.position.y.abs_diff(self.goal.y)
   }
 }
 
-impl PartialOrd for Path {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl PartialEq for Path {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost() == other.cost()
-    }
-}
-impl Eq for Path {}
-
 struct PipeNetwork {
   pipes: Vec<Vec<bool>>,
   locations: HashMap<char, Position>,
@@ -100,24 +87,8 @@ impl PipeNetwork {
   }
 
   fn shortest_path(&self, from: Position, to: Position) -> usize {
-    let mut visited = HashSet::<u16>::new();
-    let mut potentials = BinaryHeap::<Path>::new();
-    
-    let mut path = Path { goal: to, position: from, moves: 0 };
-    visited.insert(path.key());
-
-    while !path.is_solution() {
-      for neighbor in path.neighbors() {
-        if self.pipes[neighbor.position.x][neighbor.position.y] {
-          if visited.insert(neighbor.key()) {
-            potentials.push(neighbor);
-          }
-        }
-      }
-      path = potentials.pop().expect("No more paths");
-    }
-
-    path.moves
+    let path = Path { pipes: &self.pipes, goal: to, position: from };
+    a_star(path).expect("No more paths").cost
   }
 }
 
@@ -141,10 +112,10 @@ fn permutations(s: String) -> Vec<String> {
   permutation(String::new(), s)
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let network = PipeNetwork::from_input(input);
 
-  let keys: Vec<char> = network.locations.keys().map(|c| *c).collect();
+  let keys: Vec<char> = network.locations.keys().copied().collect();
   let mut distances = HashMap::<(char, char), usize>::new();
   for i in 0..network.locations.len() {
     for j in 0..network.locations.len() {
@@ -174,5 +145,5 @@ pub fn execute(input: String, part: &Part) {
     }
   }
 
-  println!("Shortest distance: {}", shortest_distance);
+  Answer::from(shortest_distance)
 }
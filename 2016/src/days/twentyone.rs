@@ -1,9 +1,6 @@
 use crate::utils::Part;
-
-enum Direction {
-  Left, Right
-}
-
+use aoc_core::direction::Direction;
+use aoc_core::Answer;
 
 enum Instruction {
   SwapPosition(usize, usize),
@@ -49,11 +46,12 @@ impl Instruction {
         let rotation_split = match d {
           Direction::Left => *a % s.len(),
           Direction::Right => s.len() - (*a % s.len()),
+          _ => panic!("Strings only rotate left or right, not {:?}", d),
         };
         format!("{}{}", &s[rotation_split..], &s[..rotation_split])
       },
       Instruction::RotateLetter(c) => {
-        let index = s.find(|x| *c == x).unwrap();
+        let index = s.find(*c).unwrap();
         let rotation_amount = 1 + index + match index >= 4 { true => 1, false => 0 };
         Instruction::RotateSteps(Direction::Right, rotation_amount).apply(s)
       },
@@ -73,12 +71,9 @@ impl Instruction {
     match self {
       Instruction::SwapPosition(a, b) => Instruction::SwapPosition(*a, *b).apply(s),
       Instruction::SwapLetter(a, b) => Instruction::SwapLetter(*a, *b).apply(s),
-      Instruction::RotateSteps(d, a) => Instruction::RotateSteps(match d {
-        Direction::Right => Direction::Left,
-        Direction::Left => Direction::Right
-      }, *a).apply(s),
+      Instruction::RotateSteps(d, a) => Instruction::RotateSteps(d.reverse(), *a).apply(s),
       Instruction::RotateLetter(c) => {
-        let index = s.find(|x| *c == x).unwrap();
+        let index = s.find(*c).unwrap();
         for i in 0..s.len() {
           let p = i + 1 + match i >= 4 { true => 1, false => 0 };
           if (index + 2*s.len() - p) % s.len() == i {
@@ -126,22 +121,21 @@ impl Instruction {
 }
 
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   match part {
     Part::PartOne => {
       let mut code = String::from("abcdefgh");
       for line in input.split("\n") {
         code = Instruction::from_string(line).apply(code);
       }
-      println!("New code: {}", code);
+      Answer::from(code)
     },
     Part::PartTwo => {
       let mut reverse_engineered = String::from("fbgdceah");
       for line in input.split("\n").collect::<Vec<&str>>().iter().rev() {
         reverse_engineered = Instruction::from_string(line).undo(reverse_engineered);
       }
-      println!("Reverse engineered code: {}", reverse_engineered);
-
+      Answer::from(reverse_engineered)
     }
   }
 }
@@ -1,4 +1,5 @@
 use crate::utils::Part;
+use aoc_core::{trace, Answer};
 
 
 enum Value {
@@ -42,8 +43,8 @@ impl Instruction {
 
   fn from_string(s: &str) -> Self {
     let mut parts = s.split(" ");
-    let verb = parts.next().expect(format!("Invalid instruction (No verb): {}", s).as_str());
-    let first_arg = parts.next().expect(format!("Invalid instruction (No args): {}", s).as_str());
+    let verb = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No verb): {}", s));
+    let first_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No args): {}", s));
     let first_value = match first_arg.parse::<isize>() {
       Ok(v) => Value::Integer(v),
       Err(_) => Value::Register(Self::register_index(first_arg)),
@@ -51,7 +52,7 @@ impl Instruction {
 
     match verb {
       "cpy" => {
-        let second_arg = parts.next().expect(format!("Invalid instruction (No second arg): {}", s).as_str());
+        let second_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No second arg): {}", s));
         Self::CopyTo(
           first_value,
           match second_arg.parse::<isize>() {
@@ -61,7 +62,7 @@ impl Instruction {
         )
       }
       "jnz" => {
-        let second_arg = parts.next().expect(format!("Invalid instruction (No second arg): {}", s).as_str());
+        let second_arg = parts.next().unwrap_or_else(|| panic!("Invalid instruction (No second arg): {}", s));
         Self::JumpIfNotZero(
           first_value,
           match second_arg.parse::<isize>() {
@@ -283,11 +284,11 @@ impl Computer {
   }
   
   #[allow(dead_code)]
-  fn print_instructions(&self) {
+  fn trace_instructions(&self) {
     for i in 0..self.instructions.len() {
       let instruction = self.instructions.get(i).unwrap();
       match instruction {
-        Instruction::CopyTo(a, b) => println!(
+        Instruction::CopyTo(a, b) => trace!(
           "{} cpy {} {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -299,7 +300,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::JumpIfNotZero(a, b) => println!(
+        Instruction::JumpIfNotZero(a, b) => trace!(
           "{} jnz {} {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -311,7 +312,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Increment(a) => println!(
+        Instruction::Increment(a) => trace!(
           "{} inc {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -319,7 +320,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Decrement(a) => println!(
+        Instruction::Decrement(a) => trace!(
           "{} dec {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -327,7 +328,7 @@ impl Computer {
             Value::Register(r) => format!("{}:{}", match r { 0=>'a',1=>'b',2=>'c',3=>'d',_=>'E' }, self.registers[*r])
           }
         ),
-        Instruction::Toggle(a) => println!(
+        Instruction::Toggle(a) => trace!(
           "{} tgl {}",
           match self.offset as usize == i {true=>"->", false=>"  "},
           match a {
@@ -344,14 +345,14 @@ impl Computer {
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let mut instructions: Vec<Instruction> = Vec::new();
   for line in input.split("\n") {
     instructions.push(Instruction::from_string(line))
   }
 
   let mut computer = Computer {
-    instructions: instructions,
+    instructions,
     registers: [match part { Part::PartOne => 7, Part::PartTwo => 12 }, 0, 0, 0],
     offset: 0,
   };
@@ -360,5 +361,5 @@ pub fn execute(input: String, part: &Part) {
     computer.compute();
   }
 
-  println!("Register A : {}", computer.registers[Instruction::register_index("a")]);
+  Answer::from(computer.registers[Instruction::register_index("a")])
 }
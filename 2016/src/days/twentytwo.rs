@@ -1,8 +1,8 @@
 use crate::utils::Part;
 use regex::Regex;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::cmp::Ordering;
-use std::collections::{HashSet, BinaryHeap};
+use aoc_core::search::{a_star, SearchState};
+use aoc_core::{trace, Answer};
 
 struct CPU {
   x: usize, y: usize,
@@ -31,32 +31,37 @@ impl Display for CPU {
 }
 
 #[derive(Copy, Clone)]
-struct Lab {
-  cpus: [[bool; 29]; 35],
+struct Lab<'a> {
+  cpus: &'a [[bool; 29]; 35],
   x: u8, y: u8,
   gx: u8, gy: u8,
-  moves: u16,
 }
 
-impl Lab {
-  fn from_cpus(cpus: Vec<CPU>) -> Self {
-    let smallest_sized_disk = cpus.iter().fold(10000, |smallest, cpu| match cpu.size < smallest { true => cpu.size, false => smallest });
+// The nodes whose data fits in any other one, the others never move
+fn movable(cpus: &[CPU]) -> [[bool; 29]; 35] {
+  let smallest_sized_disk = cpus.iter().fold(10000, |smallest, cpu| match cpu.size < smallest { true => cpu.size, false => smallest });
+  let mut movable = [[true; 29]; 35];
+  for block in cpus.iter().filter(|cpu| cpu.used > smallest_sized_disk) {
+    movable[block.x][block.y] = false;
+  }
+  movable
+}
+
+impl<'a> Lab<'a> {
+  fn from_cpus(cpus: &[CPU], movable: &'a [[bool; 29]; 35]) -> Self {
     let zero = cpus.iter().find_map(|cpu| match cpu.used == 0 { true => Some((cpu.x, cpu.y)), false => None }).unwrap();
-    let mut lab = Lab {
-      cpus: [[true; 29]; 35],
+    Lab {
+      cpus: movable,
       x: zero.0 as u8, y: zero.1 as u8,
       gx: 34, gy: 0,
-      moves: 0,
-    };
-
-    for block in cpus.iter().filter(|cpu| cpu.used > smallest_sized_disk) {
-      lab.cpus[block.x][block.y] = false;
     }
-
-    lab
   }
+}
+
+impl SearchState for Lab<'_> {
+  type Key = u32;
 
-  fn key(&self) -> u32 {
+  fn key(&self) -> Self::Key {
     let a = self.x as u32;
     let b = (self.y as u32) << 8;
     let c = (self.gx as u32) << 16;
@@ -64,16 +69,7 @@ impl Lab {
     a|b|c|d
   }
 
-  fn cost(&self) -> u16 {
-    self.moves + self.gx as u16 + self.gy as u16
-  }
-
-  fn is_solution(&self) -> bool {
-    self.gx == 0 && self.gy == 0
-  }
-
-  fn neighbors(&self) -> Vec<Self> {
-    let mut neighbors = Vec::new();
+  fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
     for (i, j) in &[(0,1),(0,-1),(1,0),(-1,0)] {
       if self.x == 0 && *i == -1 || self.x == 34 && *i == 1 || self.y == 0 && *j == -1 || self.y == 28 && *j == 1 { continue };
       let newx = (self.x as i8 + i) as u8;
@@ -82,39 +78,27 @@ impl Lab {
       if !self.cpus[newx as usize][newy as usize] { continue }
 
       let mut neighbor = *self;
-      neighbor.moves += 1;
       neighbor.x = newx;
       neighbor.y = newy;
       if newx == self.gx && newy == self.gy {
         neighbor.gx = self.x;
         neighbor.gy = self.y;
       }
-      neighbors.push(neighbor);
+      neighbours.push((neighbor, 1));
     }
-    neighbors
   }
-}
 
-impl Ord for Lab {
-  fn cmp(&self, other: &Self) -> Ordering {
-    other.cost().cmp(&self.cost())
+  fn is_goal(&self) -> bool {
+    self.gx == 0 && self.gy == 0
   }
-}
-
-impl PartialOrd for Lab {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
 
-impl PartialEq for Lab {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost() == other.cost()
-    }
+  // The goal data moves by one node at most at a time
+  fn heuristic(&self) -> usize {
+    self.gx as usize + self.gy as usize
+  }
 }
-impl Eq for Lab {}
 
-impl Display for Lab {
+impl Display for Lab<'_> {
   fn fmt(&self, f: &mut Formatter) -> FmtResult {
     let mut s = String::new();
 
@@ -130,18 +114,18 @@ impl Display for Lab {
           s.push(match self.cpus[i][j] { true => '.', false => '#' });
         }
       }
-      s.push_str("\n")
+      s.push('\n')
     }
 
     write!(f, "{}", s)
   }
 }
 
-pub fn execute(input: String, part: &Part) {
+pub fn execute(input: String, part: &Part) -> Answer {
   let re = Regex::new(r"/dev/grid").unwrap();
   let cpus: Vec<CPU> = input
     .split("\n")
-    .filter_map(|s| match re.is_match(s) { true => Some(s), false => None })
+    .filter(|s| re.is_match(s))
     .collect::<Vec<&str>>()
     .iter()
     .map(|s| CPU::from_string(s)).collect();
@@ -153,31 +137,17 @@ pub fn execute(input: String, part: &Part) {
         for j in 0..cpus.len() {
           if i == j { continue }
           if cpus[i].used > 0 && cpus[i].used <= cpus[j].free() {
-            println!("{} fits in {}", cpus[i], cpus[j]);
+            trace!("{} fits in {}", cpus[i], cpus[j]);
             fits += 1;
           }
         }
       }
-      println!("There are {} viable pairs.", fits);
+      Answer::from(fits)
     },
     Part::PartTwo => {
-      let mut visited = HashSet::<u32>::new();
-      let mut potentials = BinaryHeap::<Lab>::new();
-      
-      let mut lab = Lab::from_cpus(cpus);
-      visited.insert(lab.key());
-    
-      while !lab.is_solution() {
-        for p in lab.neighbors() {
-          if visited.insert(p.key()) {
-            potentials.push(p);
-          }
-        }
-
-        lab = potentials.pop().expect("No more potential labs");
-      }
-
-      println!("Found solution in {} moves", lab.moves);
+      let movable = movable(&cpus);
+      let lab = Lab::from_cpus(&cpus, &movable);
+      Answer::from(a_star(lab).expect("No more potential labs").cost)
     }
   }
 }
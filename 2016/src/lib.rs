@@ -0,0 +1,38 @@
+//! Solutions to the Advent of Code 2016 puzzles.
+//!
+//! The solvers report their progress through [`aoc_core::trace`], run with `--trace` to see it.
+
+mod days;
+mod utils;
+
+use std::path::PathBuf;
+use aoc_core::{Answer, SolveError, Solutions, Year};
+
+pub struct Aoc2016;
+
+impl Year for Aoc2016 {
+  fn year(&self) -> usize {
+    2016
+  }
+
+  fn solutions(&self, day: usize) -> Option<Solutions<'static>> {
+    days::solutions(day)
+  }
+
+  fn directory(&self) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+  }
+
+  fn input_directory(&self) -> PathBuf {
+    self.directory().join("inputs")
+  }
+
+  fn input_name(&self, day: usize) -> String {
+    format!("input-{:02}.txt", day)
+  }
+}
+
+/// Solves a part of a day, see [`Year::run`].
+pub fn run(day: usize, part: usize, input: &[u8]) -> Result<Answer, SolveError> {
+  Aoc2016.run(day, part, input)
+}
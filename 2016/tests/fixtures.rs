@@ -0,0 +1,6 @@
+use aoc2016::Aoc2016;
+
+#[test]
+fn puzzle_examples() {
+  aoc_core::fixtures::check(&Aoc2016);
+}
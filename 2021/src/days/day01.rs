@@ -1,15 +1,15 @@
-use crate::array_utils::sum_n;
-use crate::parser::read_unsigned_int;
+use aoc_core::array_utils::sum_n;
+use aoc_core::parser::Cursor;
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut cursor = 0;
-    let mut previous_depth = read_unsigned_int(buffer, &mut cursor);
-    cursor += 1;
+    let mut cursor = Cursor::new(buffer);
+    let mut previous_depth = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
 
     let mut increasing_times = 0;
-    while cursor < buffer.len() {
-        let depth = read_unsigned_int(buffer, &mut cursor);
-        cursor += 1;
+    while !cursor.is_at_end() {
+        let depth = cursor.read_unsigned_int().unwrap();
+        cursor.skip(1);
         if depth > previous_depth {
             increasing_times += 1;
         }
@@ -19,24 +19,24 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut cursor = 0;
+    let mut cursor = Cursor::new(buffer);
     let mut previous_depths = [0; 3];
-    previous_depths[0] = read_unsigned_int(buffer, &mut cursor);
-    cursor += 1;
-    previous_depths[1] = read_unsigned_int(buffer, &mut cursor);
-    cursor += 1;
-    previous_depths[2] = read_unsigned_int(buffer, &mut cursor);
-    cursor += 1;
+    previous_depths[0] = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
+    previous_depths[1] = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
+    previous_depths[2] = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
     let mut previous_depth = sum_n(&previous_depths, 3);
     let mut items = 0;
 
     let mut increasing_times = 0;
-    while cursor < buffer.len() {
+    while !cursor.is_at_end() {
         let mut depth = previous_depth;
         depth -= previous_depths[items];
-        previous_depths[items] = read_unsigned_int(buffer, &mut cursor);
+        previous_depths[items] = cursor.read_unsigned_int().unwrap();
         depth += previous_depths[items];
-        cursor += 1;
+        cursor.skip(1);
         if depth > previous_depth {
             increasing_times += 1;
         }
@@ -1,4 +1,4 @@
-use crate::parser::{read_unsigned_int, skip_word};
+use aoc_core::parser::{Cursor, Expected};
 
 enum Word {
     FORWARD, DOWN, UP
@@ -10,28 +10,28 @@ struct Instruction {
 }
 
 impl Instruction {
-    fn parse(buffer: &[u8], cursor: &mut usize) -> Instruction {
-        let word = match buffer[*cursor] as char {
-            'f' => Word::FORWARD,
-            'd' => Word::DOWN,
-            'u' => Word::UP,
-            _ => panic!("Unknown instruction")
+    fn parse(cursor: &mut Cursor) -> Instruction {
+        let word = match cursor.peek() {
+            Some(b'f') => Word::FORWARD,
+            Some(b'd') => Word::DOWN,
+            Some(b'u') => Word::UP,
+            _ => panic!("{}", cursor.error(Expected::OneOf(b"fdu")))
         };
-        skip_word(buffer, cursor);
-        *cursor += 1;
-        let amount = read_unsigned_int(buffer, cursor);
-        *cursor += 1;
+        cursor.skip_word();
+        cursor.skip(1);
+        let amount = cursor.read_unsigned_int().unwrap();
+        cursor.skip(1);
         Instruction { word, amount }
     }
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut cursor = 0;
+    let mut cursor = Cursor::new(buffer);
     let mut position = 0;
     let mut depth = 0;
 
-    while cursor < buffer.len() {
-        let instruction = Instruction::parse(buffer, &mut cursor);
+    while !cursor.is_at_end() {
+        let instruction = Instruction::parse(&mut cursor);
         match instruction.word {
             Word::FORWARD => {
                 position += instruction.amount;
@@ -49,13 +49,13 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut cursor = 0;
+    let mut cursor = Cursor::new(buffer);
     let mut position = 0;
     let mut aim = 0;
     let mut depth = 0;
 
-    while cursor < buffer.len() {
-        let instruction = Instruction::parse(buffer, &mut cursor);
+    while !cursor.is_at_end() {
+        let instruction = Instruction::parse(&mut cursor);
         match instruction.word {
             Word::FORWARD => {
                 position += instruction.amount;
@@ -1,7 +1,7 @@
 const AMOUNT_BITS: usize = 12;
 
 fn parse_binary_number(buffer: &[u8], cursor: &mut usize) -> u16 {
-    let mut value = 0 as u16;
+    let mut value = 0_u16;
     for _ in 0..AMOUNT_BITS {
         value <<= 1;
         value |= (buffer[*cursor] - '0' as u8) as u16;
@@ -1,6 +1,4 @@
-use std::mem::MaybeUninit;
-use std::ptr::write;
-use crate::parser::{move_to_next_line, read_unsigned_int};
+use aoc_core::parser::Cursor;
 
 const BOARD_SIDE: usize = 5;
 const BOARD_AREA: usize = BOARD_SIDE * BOARD_SIDE;
@@ -21,21 +19,18 @@ const WIN_FILTERS: [u32; 10] = [
 ];
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    unsafe {
-        let mut offset = 0;
-        #[allow(invalid_value)]
-        let mut pulled_numbers: [u8; AMOUNT_NUMBERS] = MaybeUninit::uninit().assume_init();
-        for number in &mut pulled_numbers {
-            write(number, read_unsigned_int(buffer, &mut offset) as u8);
-            offset += 1;
-        }
+    let mut cursor = Cursor::new(buffer);
+    let mut pulled_numbers = [0u8; AMOUNT_NUMBERS];
+    for number in &mut pulled_numbers {
+        *number = cursor.read_int().unwrap();
+        cursor.skip(1);
+    }
 
-        offset += 1;
-        let first_board_offset = offset;
+    cursor.skip(1);
+    let first_board_offset = cursor.offset();
 
 
-        return "".to_string()
-    }
+    return "".to_string()
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
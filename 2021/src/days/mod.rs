@@ -1,31 +1,31 @@
 mod day01;
 mod day02;
+// Unfinished, these only parse their input so far.
+#[allow(unused)]
 mod day03;
+#[allow(unused)]
 mod day04;
 
-use crate::day::{Day, Solutions};
+use aoc_core::Solutions;
 
-pub(crate) fn get_day<'a>(day: usize) -> Day<'a> {
-    Day {
-        day,
-        solutions: match day {
-            1 => Some(Solutions {
-                part1: vec![&day01::part1],
-                part2: vec![&day01::part2],
-            }),
-            2 => Some(Solutions {
-                part1: vec![&day02::part1],
-                part2: vec![&day02::part2],
-            }),
-            3 => Some(Solutions {
-                part1: vec![&day03::part1],
-                part2: vec![&day03::part2],
-            }),
-            4 => Some(Solutions {
-                part1: vec![&day04::part1],
-                part2: vec![&day04::part2],
-            }),
-            _ => None
-        }
+pub(crate) fn solutions<'a>(day: usize) -> Option<Solutions<'a>> {
+    match day {
+        1 => Some(Solutions {
+            part1: vec![("main", &day01::part1)],
+            part2: vec![("main", &day01::part2)],
+        }),
+        2 => Some(Solutions {
+            part1: vec![("main", &day02::part1)],
+            part2: vec![("main", &day02::part2)],
+        }),
+        3 => Some(Solutions {
+            part1: vec![("main", &day03::part1)],
+            part2: vec![("main", &day03::part2)],
+        }),
+        4 => Some(Solutions {
+            part1: vec![("main", &day04::part1)],
+            part2: vec![("main", &day04::part2)],
+        }),
+        _ => None
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,33 @@
+//! Solutions to the Advent of Code 2021 puzzles.
+//!
+//! ```no_run
+//! let input = std::fs::read("input/01").unwrap();
+//! let answer = aoc2021::run(1, 2, &input).unwrap();
+//! println!("{answer}");
+//! ```
+
+mod days;
+
+use std::path::PathBuf;
+use aoc_core::{Answer, SolveError, Solutions, Year};
+
+pub struct Aoc2021;
+
+impl Year for Aoc2021 {
+    fn year(&self) -> usize {
+        return 2021;
+    }
+
+    fn solutions(&self, day: usize) -> Option<Solutions<'static>> {
+        return days::solutions(day);
+    }
+
+    fn directory(&self) -> PathBuf {
+        return PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Solves a part of a day with its main variant, see [`Year::run`].
+pub fn run(day: usize, part: usize, input: &[u8]) -> Result<Answer, SolveError> {
+    return Aoc2021.run(day, part, input);
+}
@@ -0,0 +1,6 @@
+use aoc2021::Aoc2021;
+
+#[test]
+fn puzzle_examples() {
+    aoc_core::fixtures::check(&Aoc2021);
+}
@@ -1,28 +1,28 @@
-use crate::parser::read_unsigned_int;
+use aoc_core::parser::{Cursor, ParseError};
 
-fn read_elf_backpack_calories(buffer: &[u8], offset: &mut usize) -> Result<usize, &'static str> {
+fn read_elf_backpack_calories(cursor: &mut Cursor) -> Result<usize, ParseError> {
     let mut calories = 0;
 
     loop {
-        calories = match read_unsigned_int(buffer, offset) {
+        calories = match cursor.read_unsigned_int() {
             Ok(new_amount) => calories + new_amount,
             Err(e) => return Err(e)
         };
 
-        *offset += 1;
-        if *offset >= buffer.len() || buffer[*offset] == '\n' as u8 {
-            *offset += 1;
+        cursor.skip(1);
+        if cursor.is_at_end() || cursor.peek() == Some(b'\n') {
+            cursor.skip(1);
             return Ok(calories);
         }
     }
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut max_calories = 0;
-    while offset < buffer.len() {
-        let current_calories = read_elf_backpack_calories(buffer, &mut offset).unwrap();
+    while !cursor.is_at_end() {
+        let current_calories = read_elf_backpack_calories(&mut cursor).unwrap();
         if current_calories > max_calories {
             max_calories = current_calories;
         }
@@ -34,24 +34,22 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
 const AMOUNT_MAX: usize = 3;
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut max_calories = [0; AMOUNT_MAX];
     let mut current_calories;
-    let mut cursor = 0;
+    let mut rank = 0;
 
-    while offset < buffer.len() {
-        current_calories = read_elf_backpack_calories(buffer, &mut offset).unwrap();
+    while !cursor.is_at_end() {
+        current_calories = read_elf_backpack_calories(&mut cursor).unwrap();
         if current_calories > max_calories[AMOUNT_MAX - 1] {
-            while cursor < AMOUNT_MAX {
-                if current_calories > max_calories[cursor] {
-                    let previous_max = max_calories[cursor];
-                    max_calories[cursor] = current_calories;
-                    current_calories = previous_max;
+            while rank < AMOUNT_MAX {
+                if current_calories > max_calories[rank] {
+                    std::mem::swap(&mut max_calories[rank], &mut current_calories);
                 }
-                cursor += 1;
+                rank += 1;
             }
-            cursor = 0;
+            rank = 0;
         }
     }
 
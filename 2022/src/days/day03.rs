@@ -1,7 +1,7 @@
 fn get_priority(letter: u8) -> u8 {
-    match (letter as u8) < ('a' as u8) {
-        true => letter as u8 - 'A' as u8 + 26,
-        false => letter as u8 - 'a' as u8,
+    match letter < ('a' as u8) {
+        true => letter - 'A' as u8 + 26,
+        false => letter - 'a' as u8,
     }
 }
 
@@ -24,12 +24,12 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
         while buffer[offset] != '\n' as u8 { offset += 1 }
         let half_offset = (start_offset + offset) / 2;
 
-        let mut left = 0 as u64;
+        let mut left = 0_u64;
         for c in start_offset..half_offset {
             left |= 1 << get_priority(buffer[c]);
         }
 
-        let mut right = 0 as u64;
+        let mut right = 0_u64;
         for c in half_offset..offset {
             right |= 1 << get_priority(buffer[c]);
         }
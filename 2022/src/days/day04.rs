@@ -1,23 +1,23 @@
-use crate::parser::read_unsigned_int;
+use aoc_core::parser::Cursor;
 
-fn parse_assignement(buffer: &[u8], offset: &mut usize) -> (usize, usize, usize, usize) {
-    let start_a = read_unsigned_int(buffer, offset).unwrap();
-    *offset += 1;
-    let end_a = read_unsigned_int(buffer, offset).unwrap();
-    *offset += 1;
-    let start_b = read_unsigned_int(buffer, offset).unwrap();
-    *offset += 1;
-    let end_b = read_unsigned_int(buffer, offset).unwrap();
-    *offset += 1;
+fn parse_assignement(cursor: &mut Cursor) -> (usize, usize, usize, usize) {
+    let start_a = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
+    let end_a = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
+    let start_b = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
+    let end_b = cursor.read_unsigned_int().unwrap();
+    cursor.skip(1);
     return (start_a, end_a, start_b, end_b);
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut contained = 0;
-    while offset < buffer.len() {
-        let (start_a, end_a, start_b, end_b) = parse_assignement(buffer, &mut offset);
+    while !cursor.is_at_end() {
+        let (start_a, end_a, start_b, end_b) = parse_assignement(&mut cursor);
         if start_b >= start_a && end_b <= end_a || start_a >= start_b && end_a <= end_b {
             contained += 1;
         }
@@ -27,11 +27,11 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut overlaping = 0;
-    while offset < buffer.len() {
-        let (start_a, end_a, start_b, end_b) = parse_assignement(buffer, &mut offset);
+    while !cursor.is_at_end() {
+        let (start_a, end_a, start_b, end_b) = parse_assignement(&mut cursor);
         if !(end_a < start_b || end_b < start_a) {
             overlaping += 1;
         }
@@ -1,7 +1,8 @@
 use std::alloc::{alloc, dealloc, Layout};
 use std::marker::PhantomData;
 use std::ptr::{copy, write, NonNull, read};
-use crate::parser::read_unsigned_int;
+use aoc_core::parse;
+use aoc_core::parser::Cursor;
 
 pub trait Inventory {
     fn crate_mover_9000(&mut self, amount: usize, from: usize, to: usize);
@@ -27,7 +28,7 @@ impl MemoryInventory {
         assert!(layout.size() <= isize::MAX as usize, "Allocation too large");
 
         MemoryInventory {
-            ptr: NonNull::new(unsafe { alloc(layout) } as *mut u8).unwrap(),
+            ptr: NonNull::new(unsafe { alloc(layout) }).unwrap(),
             cap,
             _marker: PhantomData,
             heights: [0; MAX_CRATE_STACKS],
@@ -43,7 +44,7 @@ impl Inventory for MemoryInventory {
                 copy(
                     self.ptr.as_ptr().add(from * MAX_CRATE_STACK_HEIGHT + self.heights[from] - i - 1),
                     self.ptr.as_ptr().add(to * MAX_CRATE_STACK_HEIGHT + self.heights[to] + i),
-                    amount,
+                    1,
                 );
             }
         }
@@ -83,7 +84,7 @@ impl Drop for MemoryInventory {
     fn drop(&mut self) {
         unsafe {
             dealloc(
-                self.ptr.as_ptr() as *mut u8,
+                self.ptr.as_ptr(),
                 Layout::array::<u8>(self.cap).unwrap(),
             );
         }
@@ -142,57 +143,53 @@ impl Inventory for StackInventory {
 }
 
 fn get_amount_stacks(buffer: &[u8]) -> usize {
-    let mut offset = 3;
+    let mut cursor = Cursor::new(buffer);
+    cursor.skip(3);
     let mut stacks = 1;
-    while buffer[offset] != '\n' as u8 {
+    while cursor.peek().is_some_and(|byte| byte != b'\n') {
         stacks += 1;
-        offset += 4;
+        cursor.skip(4);
     }
     return stacks;
 }
 
 fn get_highest_stack(buffer: &[u8], amount_stacks: usize) -> usize {
-    let mut offset = amount_stacks * 4;
+    let mut cursor = Cursor::new(buffer);
+    cursor.skip(amount_stacks * 4);
     let mut height = 0;
-    while buffer[offset] != '\n' as u8 {
+    while cursor.peek().is_some_and(|byte| byte != b'\n') {
         height += 1;
-        offset += amount_stacks * 4;
+        cursor.skip(amount_stacks * 4);
     }
     return height;
 }
 
-fn parse_crate_stacks(buffer: &[u8], offset: &mut usize, inventory_generator: impl Fn(usize) -> Box<dyn Inventory>) -> Box<dyn Inventory> {
+fn parse_crate_stacks(buffer: &[u8], cursor: &mut Cursor, inventory_generator: impl Fn(usize) -> Box<dyn Inventory>) -> Box<dyn Inventory> {
     let amount_stacks = get_amount_stacks(buffer);
     let highest_stack = get_highest_stack(buffer, amount_stacks);
 
     let mut inventory = inventory_generator(amount_stacks);
 
     let mut height = highest_stack - 1;
-    *offset += 1;
+    cursor.skip(1);
     loop {
         for stack in 0..amount_stacks {
-            if buffer[*offset] != ' ' as u8 {
-                inventory.set_crate(stack, height, buffer[*offset]);
+            if let Some(value) = cursor.peek().filter(|byte| *byte != b' ') {
+                inventory.set_crate(stack, height, value);
             }
-            *offset += 4;
+            cursor.skip(4);
         }
 
         if height == 0 { break }
         height -= 1;
     }
-    *offset += amount_stacks * 4;
+    cursor.skip(amount_stacks * 4);
     return inventory
 }
 
-fn parse_instruction(buffer: &[u8], offset: &mut usize) -> (usize, usize, usize) {
-    *offset += 5;
-    let amount = read_unsigned_int(buffer, offset).unwrap();
-    *offset += 6;
-    let from = read_unsigned_int(buffer, offset).unwrap() - 1;
-    *offset += 4;
-    let to = read_unsigned_int(buffer, offset).unwrap() - 1;
-    *offset += 1;
-    return (amount, from, to);
+fn parse_instruction(cursor: &mut Cursor) -> (usize, usize, usize) {
+    let (amount, from, to) = parse!(cursor, "move " {usize} " from " {usize} " to " {usize} "\n").unwrap();
+    return (amount, from - 1, to - 1);
 }
 
 fn generate_stack_inventory(amount_stacks: usize) -> Box<dyn Inventory> {
@@ -205,16 +202,17 @@ fn generate_memory_inventory(amount_stacks: usize) -> Box<dyn Inventory> {
 
 
 pub(crate) fn part1(buffer: &[u8], inventory_generator: impl Fn(usize) -> Box<dyn Inventory>) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
-    let mut inventory = parse_crate_stacks(buffer, &mut offset, inventory_generator);
-    while offset < buffer.len() {
-        let (amount, from, to) = parse_instruction(buffer, &mut offset);
+    let mut inventory = parse_crate_stacks(buffer, &mut cursor, inventory_generator);
+    while !cursor.is_at_end() {
+        let (amount, from, to) = parse_instruction(&mut cursor);
         inventory.crate_mover_9000(amount, from, to);
     }
 
-    let mut top = String::with_capacity(MAX_CRATE_STACKS);
-    for stack in 0..MAX_CRATE_STACKS {
+    let amount_stacks = get_amount_stacks(buffer);
+    let mut top = String::with_capacity(amount_stacks);
+    for stack in 0..amount_stacks {
         unsafe { top.as_mut_vec().push(inventory.get_top_crate(stack)) }
     }
 
@@ -222,24 +220,25 @@ pub(crate) fn part1(buffer: &[u8], inventory_generator: impl Fn(usize) -> Box<dy
 }
 
 pub(crate) fn part1_memory(buffer: &[u8]) -> String {
-    part1(buffer, &generate_memory_inventory)
+    part1(buffer, generate_memory_inventory)
 }
 
 pub(crate) fn part1_stack(buffer: &[u8]) -> String {
-    part1(buffer, &generate_stack_inventory)
+    part1(buffer, generate_stack_inventory)
 }
 
 pub(crate) fn part2(buffer: &[u8], inventory_generator: impl Fn(usize) -> Box<dyn Inventory>) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
-    let mut inventory = parse_crate_stacks(buffer, &mut offset, inventory_generator);
-    while offset < buffer.len() {
-        let (amount, from, to) = parse_instruction(buffer, &mut offset);
+    let mut inventory = parse_crate_stacks(buffer, &mut cursor, inventory_generator);
+    while !cursor.is_at_end() {
+        let (amount, from, to) = parse_instruction(&mut cursor);
         inventory.crate_mover_9001(amount, from, to);
     }
 
-    let mut top = String::with_capacity(MAX_CRATE_STACKS);
-    for stack in 0..MAX_CRATE_STACKS {
+    let amount_stacks = get_amount_stacks(buffer);
+    let mut top = String::with_capacity(amount_stacks);
+    for stack in 0..amount_stacks {
         unsafe { top.as_mut_vec().push(inventory.get_top_crate(stack)) }
     }
 
@@ -247,9 +246,131 @@ pub(crate) fn part2(buffer: &[u8], inventory_generator: impl Fn(usize) -> Box<dy
 }
 
 pub(crate) fn part2_memory(buffer: &[u8]) -> String {
-    part2(buffer, &generate_memory_inventory)
+    part2(buffer, generate_memory_inventory)
 }
 
 pub(crate) fn part2_stack(buffer: &[u8]) -> String {
-    part2(buffer, &generate_stack_inventory)
+    part2(buffer, generate_stack_inventory)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    /// Between two and nine stacks, with few enough crates for any stack to hold all of them.
+    fn stacks(min_height: usize) -> impl Strategy<Value = Vec<Vec<u8>>> {
+        return (2..=MAX_CRATE_STACKS).prop_flat_map(move |amount_stacks| {
+            let crates = prop::collection::vec(b'A'..=b'Z', min_height..=MAX_CRATE_STACK_HEIGHT / amount_stacks);
+            return prop::collection::vec(crates, amount_stacks);
+        });
+    }
+
+    fn moves() -> impl Strategy<Value = Vec<(usize, usize, usize)>> {
+        return prop::collection::vec((any::<usize>(), any::<usize>(), any::<usize>()), 0..64);
+    }
+
+    /// Turns random numbers into a move between two different stacks, leaving at least `keep`
+    /// crates on the stack it takes from.
+    fn valid_move(stacks: &[Vec<u8>], (from, to, amount): (usize, usize, usize), keep: usize) -> Option<(usize, usize, usize)> {
+        let candidates: Vec<usize> = (0..stacks.len()).filter(|&stack| stacks[stack].len() > keep).collect();
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let from = candidates[from % candidates.len()];
+        let to = (from + 1 + to % (stacks.len() - 1)) % stacks.len();
+        let amount = 1 + amount % (stacks[from].len() - keep);
+        return Some((amount, from, to));
+    }
+
+    /// The reference crane, moving crates one at a time or all at once.
+    fn apply_move(stacks: &mut [Vec<u8>], (amount, from, to): (usize, usize, usize), all_at_once: bool) {
+        let mut moved = stacks[from].split_off(stacks[from].len() - amount);
+        if !all_at_once {
+            moved.reverse();
+        }
+        stacks[to].extend(moved);
+    }
+
+    fn render(stacks: &[Vec<u8>], moves: &[(usize, usize, usize)]) -> String {
+        let mut input = String::new();
+        let highest = stacks.iter().map(|stack| stack.len()).max().unwrap();
+        for height in (0..highest).rev() {
+            let row: Vec<String> = stacks.iter()
+                .map(|stack| match stack.get(height) {
+                    Some(value) => format!("[{}]", *value as char),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            input.push_str(&format!("{}\n", row.join(" ")));
+        }
+        let labels: Vec<String> = (1..=stacks.len()).map(|stack| format!(" {stack} ")).collect();
+        input.push_str(&format!("{}\n\n", labels.join(" ")));
+        for (amount, from, to) in moves {
+            input.push_str(&format!("move {amount} from {} to {}\n", from + 1, to + 1));
+        }
+        return input;
+    }
+
+    fn top_crates(stacks: &[Vec<u8>]) -> String {
+        return stacks.iter().map(|stack| *stack.last().unwrap() as char).collect();
+    }
+
+    proptest! {
+        #[test]
+        fn inventories_agree(mut stacks in stacks(0), moves in moves(), all_at_once in any::<bool>()) {
+            let mut inventories = [generate_stack_inventory(stacks.len()), generate_memory_inventory(stacks.len())];
+            for inventory in &mut inventories {
+                for (stack, crates) in stacks.iter().enumerate() {
+                    for (height, value) in crates.iter().enumerate() {
+                        inventory.set_crate(stack, height, *value);
+                    }
+                }
+            }
+
+            for random_move in moves {
+                let Some((amount, from, to)) = valid_move(&stacks, random_move, 0) else {
+                    break;
+                };
+                apply_move(&mut stacks, (amount, from, to), all_at_once);
+                for inventory in &mut inventories {
+                    match all_at_once {
+                        true => inventory.crate_mover_9001(amount, from, to),
+                        false => inventory.crate_mover_9000(amount, from, to),
+                    }
+                }
+
+                for (stack, crates) in stacks.iter().enumerate() {
+                    if let Some(top) = crates.last() {
+                        for inventory in &inventories {
+                            prop_assert_eq!(inventory.get_top_crate(stack) as char, *top as char);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn variants_agree(stacks in stacks(1), moves in moves()) {
+            // Both cranes leave the same amount of crates on every stack, so the moves stay valid for both.
+            let mut crate_mover_9000 = stacks.clone();
+            let mut crate_mover_9001 = stacks.clone();
+            let mut valid_moves = Vec::new();
+            for random_move in moves {
+                let Some(valid_move) = valid_move(&crate_mover_9000, random_move, 1) else {
+                    break;
+                };
+                apply_move(&mut crate_mover_9000, valid_move, false);
+                apply_move(&mut crate_mover_9001, valid_move, true);
+                valid_moves.push(valid_move);
+            }
+
+            let input = render(&stacks, &valid_moves);
+            prop_assert_eq!(part1_stack(input.as_bytes()), top_crates(&crate_mover_9000));
+            prop_assert_eq!(part1_memory(input.as_bytes()), top_crates(&crate_mover_9000));
+            prop_assert_eq!(part2_stack(input.as_bytes()), top_crates(&crate_mover_9001));
+            prop_assert_eq!(part2_memory(input.as_bytes()), top_crates(&crate_mover_9001));
+        }
+    }
 }
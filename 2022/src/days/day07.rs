@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap};
-use crate::parser::{move_to_next_line, read_unsigned_int};
+use aoc_core::parser::Cursor;
 
 const FILE_MAX_SIZE: usize = 100000;
 const SYSTEM_MAX_SIZE: usize = 70000000;
@@ -25,7 +25,7 @@ impl PartialEq<Self> for FolderContents {
 
 impl PartialOrd<Self> for FolderContents {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Option::from(self.cmp(other))
+        Some(self.cmp(other))
     }
 }
 
@@ -44,29 +44,29 @@ impl FolderContents {
     }
 }
 
-fn parse_item_p1(buffer: &[u8], offset: &mut usize) -> Option<ItemType> {
-    if buffer[*offset] == '$' as u8 {
+fn parse_item_p1(cursor: &mut Cursor) -> Option<ItemType> {
+    if cursor.peek() == Some(b'$') {
         return None
     }
 
-    if buffer[*offset] == 'd' as u8 {
-        move_to_next_line(buffer, offset);
+    if cursor.peek() == Some(b'd') {
+        cursor.move_to_next_line();
         return Some(ItemType::FOLDER());
     }
 
-    let file_size = read_unsigned_int(buffer, offset).unwrap();
-    move_to_next_line(buffer, offset);
+    let file_size = cursor.read_unsigned_int().unwrap();
+    cursor.move_to_next_line();
     return Some(ItemType::FILE(file_size));
 }
 
-fn ls_p1(buffer: &[u8], offset: &mut usize) -> FolderContents {
+fn ls_p1(cursor: &mut Cursor) -> FolderContents {
     // ls instruction
-    move_to_next_line(buffer, offset);
+    cursor.move_to_next_line();
 
     let mut contents = FolderContents::empty();
 
-    while *offset < buffer.len() {
-        let item = parse_item_p1(buffer, offset);
+    while !cursor.is_at_end() {
+        let item = parse_item_p1(cursor);
         if item.is_none() {
             break;
         }
@@ -80,25 +80,25 @@ fn ls_p1(buffer: &[u8], offset: &mut usize) -> FolderContents {
     return contents;
 }
 
-fn read_folder_p1(buffer: &[u8], offset: &mut usize) -> (usize, usize) {
-    let mut folder_contents = ls_p1(buffer, offset);
+fn read_folder_p1(cursor: &mut Cursor) -> (usize, usize) {
+    let mut folder_contents = ls_p1(cursor);
     let mut answer = 0;
     while folder_contents.remaining_folders > 0 {
         // cd in
-        //move_to_next_line(buffer, offset);
-        move_to_next_line(buffer, offset);
+        //cursor.move_to_next_line();
+        cursor.move_to_next_line();
 
-        let (total, part_of_the_answer) = read_folder_p1(buffer, offset);
+        let (total, part_of_the_answer) = read_folder_p1(cursor);
         answer += part_of_the_answer;
         folder_contents.size += total;
         folder_contents.remaining_folders -= 1;
         // cd out
-        if *offset < buffer.len() {
-            move_to_next_line(buffer, offset);
+        if !cursor.is_at_end() {
+            cursor.move_to_next_line();
         }
     }
 
-    if folder_contents.size < FILE_MAX_SIZE {
+    if folder_contents.size <= FILE_MAX_SIZE {
         answer += folder_contents.size;
     }
 
@@ -107,38 +107,38 @@ fn read_folder_p1(buffer: &[u8], offset: &mut usize) -> (usize, usize) {
 
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     // cd /
-    move_to_next_line(buffer, &mut offset);
-    let (_, answer) = read_folder_p1(buffer, &mut offset);
+    cursor.move_to_next_line();
+    let (_, answer) = read_folder_p1(&mut cursor);
 
     return answer.to_string()
 }
 
-fn parse_item_p2(buffer: &[u8], offset: &mut usize) -> Option<ItemType> {
-    if buffer[*offset] == '$' as u8 {
+fn parse_item_p2(cursor: &mut Cursor) -> Option<ItemType> {
+    if cursor.peek() == Some(b'$') {
         return None
     }
 
-    if buffer[*offset] == 'd' as u8 {
-        move_to_next_line(buffer, offset);
+    if cursor.peek() == Some(b'd') {
+        cursor.move_to_next_line();
         return Some(ItemType::FOLDER());
     }
 
-    let file_size = read_unsigned_int(buffer, offset).unwrap();
-    move_to_next_line(buffer, offset);
+    let file_size = cursor.read_unsigned_int().unwrap();
+    cursor.move_to_next_line();
     return Some(ItemType::FILE(file_size));
 }
 
-fn ls_p2(buffer: &[u8], offset: &mut usize) -> FolderContents {
+fn ls_p2(cursor: &mut Cursor) -> FolderContents {
     // ls instruction
-    move_to_next_line(buffer, offset);
+    cursor.move_to_next_line();
 
     let mut contents = FolderContents::empty();
 
-    while *offset < buffer.len() {
-        let item = parse_item_p2(buffer, offset);
+    while !cursor.is_at_end() {
+        let item = parse_item_p2(cursor);
         if item.is_none() {
             break;
         }
@@ -152,19 +152,19 @@ fn ls_p2(buffer: &[u8], offset: &mut usize) -> FolderContents {
     return contents;
 }
 
-fn read_folder_p2(buffer: &[u8], offset: &mut usize, folders: &mut BinaryHeap<FolderContents>) -> usize {
-    let mut folder_contents = ls_p2(buffer, offset);
+fn read_folder_p2(cursor: &mut Cursor, folders: &mut BinaryHeap<FolderContents>) -> usize {
+    let mut folder_contents = ls_p2(cursor);
     while folder_contents.remaining_folders > 0 {
         // cd in
-        //move_to_next_line(buffer, offset);
-        move_to_next_line(buffer, offset);
+        //cursor.move_to_next_line();
+        cursor.move_to_next_line();
 
-        let total = read_folder_p2(buffer, offset, folders);
+        let total = read_folder_p2(cursor, folders);
         folder_contents.size += total;
         folder_contents.remaining_folders -= 1;
         // cd out
-        if *offset < buffer.len() {
-            move_to_next_line(buffer, offset);
+        if !cursor.is_at_end() {
+            cursor.move_to_next_line();
         }
     }
 
@@ -174,13 +174,13 @@ fn read_folder_p2(buffer: &[u8], offset: &mut usize, folders: &mut BinaryHeap<Fo
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut folders = BinaryHeap::<FolderContents>::new();
 
     // cd /
-    move_to_next_line(buffer, &mut offset);
-    let total_size = read_folder_p2(buffer, &mut offset, &mut folders);
+    cursor.move_to_next_line();
+    let total_size = read_folder_p2(&mut cursor, &mut folders);
     let remaining_place = SYSTEM_MAX_SIZE - total_size;
     let space_to_clear = SYSTEM_REQUIRED_SIZE - remaining_place;
 
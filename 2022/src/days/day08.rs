@@ -1,16 +1,14 @@
 use std::alloc::{alloc, dealloc, Layout};
 use std::marker::PhantomData;
 use std::ptr::NonNull;
+use aoc_core::direction::Direction;
+use aoc_core::grid::Grid;
 
 const GRID_SIZE: usize = 99;
 const TREE_COUNT: usize = GRID_SIZE * GRID_SIZE;
 const SECTION_SIZE: usize = 64;
 const AMOUNT_SECTIONS: usize = (TREE_COUNT + GRID_SIZE) / SECTION_SIZE + 1;
 
-enum Direction {
-    UP, DOWN, LEFT, RIGHT
-}
-
 struct Visibility {
     visible: [u64; AMOUNT_SECTIONS],
     amount_visible: usize,
@@ -37,22 +35,20 @@ impl Visibility {
 
 fn scan_row(buffer: &[u8], forest_size: usize, direction: Direction, index: usize, visibility: &mut Visibility, stop_at: u8) -> u8 {
     let mut offset = match direction {
-        Direction::UP => (forest_size + 1) * (forest_size - 1) + index,
-        Direction::DOWN => index,
-        Direction::LEFT => (forest_size + 1) * (index + 1) - 2,
-        Direction::RIGHT => (forest_size + 1) * index,
+        Direction::Up => (forest_size + 1) * (forest_size - 1) + index,
+        Direction::Down => index,
+        Direction::Left => (forest_size + 1) * (index + 1) - 2,
+        Direction::Right => (forest_size + 1) * index,
+        _ => unreachable!("The trees are only seen along the rows and columns"),
     };
+    let step = direction.step::<isize>();
+    let stride = step.y * (forest_size + 1) as isize + step.x;
 
     let mut height = buffer[offset];
     visibility.set_visible(offset);
 
     for _ in 0..forest_size-1 {
-        match direction {
-            Direction::UP => offset -= forest_size + 1,
-            Direction::DOWN => offset += forest_size + 1,
-            Direction::LEFT => offset -= 1,
-            Direction::RIGHT => offset += 1,
-        }
+        offset = offset.wrapping_add_signed(stride);
 
         if buffer[offset] > height {
             visibility.set_visible(offset);
@@ -72,17 +68,17 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
     let mut visibility = Visibility::new();
 
     for index in 0..GRID_SIZE {
-        let stop_at = scan_row(buffer, GRID_SIZE, Direction::LEFT, index, &mut visibility, '9' as u8);
-        scan_row(buffer, GRID_SIZE, Direction::RIGHT, index, &mut visibility, stop_at);
-        let stop_at = scan_row(buffer, GRID_SIZE, Direction::UP, index, &mut visibility, '9' as u8);
-        scan_row(buffer, GRID_SIZE, Direction::DOWN, index, &mut visibility, stop_at);
+        let stop_at = scan_row(buffer, GRID_SIZE, Direction::Left, index, &mut visibility, '9' as u8);
+        scan_row(buffer, GRID_SIZE, Direction::Right, index, &mut visibility, stop_at);
+        let stop_at = scan_row(buffer, GRID_SIZE, Direction::Up, index, &mut visibility, '9' as u8);
+        scan_row(buffer, GRID_SIZE, Direction::Down, index, &mut visibility, stop_at);
     }
 
     return visibility.amount_visible.to_string()
 }
 
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 struct Peak {
     position: usize,
     height: u8,
@@ -178,11 +174,11 @@ impl StripSurveyor for MemStripSurveyor {
                 self.amount_descending = 1;
             } else {
                 let mut backward_offset = self.amount_descending;
-                while backward_offset > 0 && self.ptr.offset(backward_offset + 9).read().height >= height {
+                while backward_offset > 0 && self.ptr.offset(backward_offset + 9).read().height <= height {
                     backward_offset -= 1;
                 }
                 self.ptr.offset(10 + backward_offset).write(Peak { position: self.offset, height });
-                self.amount_descending = backward_offset;
+                self.amount_descending = backward_offset + 1;
             }
 
             self.offset += 1;
@@ -230,8 +226,8 @@ impl MemForestSurveyor {
             };
 
             for i in 0..GRID_SIZE {
-                surveyor.columns[i].ptr = ptr.as_ptr().offset((i * 2 * 10) as isize);
-                surveyor.rows[i].ptr = ptr.as_ptr().offset(((i + GRID_SIZE) * 2 * 10) as isize);
+                surveyor.columns[i].ptr = ptr.as_ptr().add(i * 2 * 10);
+                surveyor.rows[i].ptr = ptr.as_ptr().add((i + GRID_SIZE) * 2 * 10);
             }
 
             return surveyor;
@@ -258,6 +254,7 @@ fn generate_memory_forest_surveyor() -> Box<dyn ForestSurveyor> {
     Box::new(MemForestSurveyor::new())
 }
 
+#[allow(clippy::explicit_counter_loop)]
 fn part1_unfinished_forest_surveyor(buffer: &[u8], calculator_generator: impl Fn() -> Box<dyn ForestSurveyor>) -> String {
     let mut offset = 0;
     let mut calculators = calculator_generator();
@@ -306,16 +303,10 @@ impl BackwardSurveyor {
             self.descending[0] = Peak { position: offset, height };
             self.amount_descending = 1;
         } else {
-            let previous_first_seen = self.descending[self.amount_descending-1];
-            if previous_first_seen.height == height {
-                return;
-            }
-
+            // A tree of the same height now blocks the view before the previous one
             let mut write_at = self.amount_descending;
-            if previous_first_seen.height < height {
-                while write_at > 0 && self.descending[write_at - 1].height <= height {
-                    write_at -= 1;
-                }
+            while write_at > 0 && self.descending[write_at - 1].height <= height {
+                write_at -= 1;
             }
             self.descending[write_at] = Peak { position: offset, height };
             self.amount_descending = write_at + 1;
@@ -335,7 +326,7 @@ impl BackwardSurveyor {
 
         return match self.descending[viewing].height >= threshold {
             true => from - self.descending[viewing].position,
-            false => from - 0,
+            false => from,
         } as u32;
     }
 }
@@ -354,35 +345,22 @@ fn scan_trees(buffer: &[u8], direction: Direction, index: usize, visibility: &mu
     };
 
     let (mut x, mut y) = match direction {
-        Direction::UP => (index, GRID_SIZE - 1),
-        Direction::DOWN => (index, 0),
-        Direction::LEFT => (GRID_SIZE - 1, index),
-        Direction::RIGHT => (0, index),
+        Direction::Up => (index, GRID_SIZE - 1),
+        Direction::Down => (index, 0),
+        Direction::Left => (GRID_SIZE - 1, index),
+        Direction::Right => (0, index),
+        _ => unreachable!("The trees are only seen along the rows and columns"),
     };
     let mut offset = (GRID_SIZE + 1) * y + x;
+    let step = direction.step::<isize>();
+    let stride = step.y * (GRID_SIZE + 1) as isize + step.x;
 
     surveyor.move_forward(buffer[offset], 0);
 
     for distance in 1..GRID_SIZE-1 {
-
-        match direction {
-            Direction::UP => {
-                offset -= GRID_SIZE + 1;
-                y -= 1;
-            },
-            Direction::DOWN => {
-                offset += GRID_SIZE + 1;
-                y += 1;
-            },
-            Direction::LEFT => {
-                offset -= 1;
-                x -= 1;
-            },
-            Direction::RIGHT => {
-                offset += 1;
-                x += 1;
-            },
-        }
+        offset = offset.wrapping_add_signed(stride);
+        x = x.wrapping_add_signed(step.x);
+        y = y.wrapping_add_signed(step.y);
 
         let height = buffer[offset];
 
@@ -402,10 +380,10 @@ pub(crate) fn part2_surveyor(buffer: &[u8]) -> String {
     };
 
     for index in 1..GRID_SIZE-1 {
-        scan_trees(buffer, Direction::LEFT, index, &mut state);
-        scan_trees(buffer, Direction::RIGHT, index, &mut state);
-        scan_trees(buffer, Direction::UP, index, &mut state);
-        scan_trees(buffer, Direction::DOWN, index, &mut state);
+        scan_trees(buffer, Direction::Left, index, &mut state);
+        scan_trees(buffer, Direction::Right, index, &mut state);
+        scan_trees(buffer, Direction::Up, index, &mut state);
+        scan_trees(buffer, Direction::Down, index, &mut state);
     }
 
     return state.highest_score.to_string()
@@ -476,4 +454,114 @@ pub(crate) fn part2(buffer: &[u8]) -> String {
     }
 
     return max_score.to_string()
-}
\ No newline at end of file
+}
+
+/// The trees seen from a tree of the given height looking over `trees`, up to the first one as
+/// tall as it.
+fn viewing_distance<'a>(trees: impl Iterator<Item = &'a u8>, height: u8) -> usize {
+    let mut seen = 0;
+    for tree in trees {
+        seen += 1;
+        if *tree >= height {
+            break;
+        }
+    }
+    return seen;
+}
+
+pub(crate) fn part1_grid(buffer: &[u8]) -> String {
+    let rows = Grid::parse(buffer, |tree| tree).unwrap();
+    let columns = rows.transpose();
+
+    let visible = rows.positions().filter(|position| {
+        let (row, column) = (rows.row(position.y), columns.row(position.x));
+        let height = row[position.x];
+        return [&row[..position.x], &row[position.x + 1..], &column[..position.y], &column[position.y + 1..]]
+            .iter()
+            .any(|trees| trees.iter().all(|tree| *tree < height));
+    });
+    return visible.count().to_string();
+}
+
+pub(crate) fn part2_grid(buffer: &[u8]) -> String {
+    let rows = Grid::parse(buffer, |tree| tree).unwrap();
+    let columns = rows.transpose();
+
+    let scores = rows.positions().map(|position| {
+        let (row, column) = (rows.row(position.y), columns.row(position.x));
+        let height = row[position.x];
+        return viewing_distance(row[..position.x].iter().rev(), height)
+            * viewing_distance(row[position.x + 1..].iter(), height)
+            * viewing_distance(column[..position.y].iter().rev(), height)
+            * viewing_distance(column[position.y + 1..].iter(), height);
+    });
+    return scores.max().unwrap().to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    fn forest() -> impl Strategy<Value = Vec<u8>> {
+        return prop::collection::vec(b'0'..=b'9', TREE_COUNT).prop_map(|trees| {
+            let mut buffer = Vec::with_capacity(TREE_COUNT + GRID_SIZE);
+            for row in trees.chunks(GRID_SIZE) {
+                buffer.extend_from_slice(row);
+                buffer.push(b'\n');
+            }
+            return buffer;
+        });
+    }
+
+    fn stack_peaks(surveyor: &StackStripSurveyor) -> (Vec<Peak>, Vec<Peak>) {
+        return (
+            surveyor.ascending[..surveyor.amount_ascending].to_vec(),
+            surveyor.descending[..surveyor.amount_descending].to_vec(),
+        );
+    }
+
+    fn mem_peaks(surveyor: &MemStripSurveyor) -> (Vec<Peak>, Vec<Peak>) {
+        unsafe {
+            return (
+                (0..surveyor.amount_ascending).map(|peak| surveyor.ptr.offset(peak).read()).collect(),
+                (0..surveyor.amount_descending).map(|peak| surveyor.ptr.offset(10 + peak).read()).collect(),
+            );
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn surveyors_agree(buffer in forest()) {
+            let mut stack_surveyor = Box::new(StackForestSurveyor::new());
+            let mut mem_surveyor = MemForestSurveyor::new();
+            for row in 0..GRID_SIZE {
+                for column in 0..GRID_SIZE {
+                    let height = buffer[(GRID_SIZE + 1) * row + column];
+                    for surveyor in [&mut *stack_surveyor as &mut dyn ForestSurveyor, &mut mem_surveyor] {
+                        surveyor.move_row_forward(row, height);
+                        surveyor.move_col_forward(column, height);
+                    }
+                }
+            }
+
+            for index in 0..GRID_SIZE {
+                prop_assert_eq!(stack_peaks(&stack_surveyor.rows[index]), mem_peaks(&mem_surveyor.rows[index]));
+                prop_assert_eq!(stack_peaks(&stack_surveyor.columns[index]), mem_peaks(&mem_surveyor.columns[index]));
+            }
+        }
+
+        #[test]
+        fn visible_trees_agree(buffer in forest()) {
+            prop_assert_eq!(part1_grid(&buffer), part1(&buffer));
+        }
+
+        #[test]
+        fn scenic_scores_agree(buffer in forest()) {
+            prop_assert_eq!(part2_surveyor(&buffer), part2(&buffer));
+            prop_assert_eq!(part2_grid(&buffer), part2(&buffer));
+        }
+    }
+}
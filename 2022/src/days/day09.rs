@@ -1,10 +1,8 @@
 use std::collections::HashSet;
-use crate::parser::read_unsigned_int;
-use crate::vector::Vector2D;
-
-enum Direction {
-    LEFT, RIGHT, UP, DOWN
-}
+use aoc_core::direction::Direction;
+use aoc_core::parse;
+use aoc_core::parser::Cursor;
+use aoc_core::vector::Vector2D;
 
 struct Instruction {
     direction: Direction,
@@ -12,24 +10,14 @@ struct Instruction {
 }
 
 impl Instruction {
-    fn parse(buffer: &[u8], offset: &mut usize) -> Self {
-        let char = buffer[*offset] as char;
-        *offset += 2;
-        let amount = read_unsigned_int(buffer, offset).unwrap();
-        *offset += 1;
-
-        match char {
-            'L' => Instruction { direction: Direction::LEFT, distance: amount },
-            'R' => Instruction { direction: Direction::RIGHT, distance: amount },
-            'D' => Instruction { direction: Direction::DOWN, distance: amount },
-            'U' => Instruction { direction: Direction::UP, distance: amount },
-            _ => panic!("Unknown Direction: {}", char),
-        }
+    fn parse(cursor: &mut Cursor) -> Self {
+        let (direction, distance) = parse!(cursor, {Direction} " " {usize} "\n").unwrap();
+        Instruction { direction, distance }
     }
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut head = Vector2D { x: 0, y: 0 };
     let mut tail = Vector2D { x: 0, y: 0 };
@@ -37,48 +25,16 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
     let mut visited = HashSet::<Vector2D<i16>>::with_capacity(buffer.len() / 4);
     visited.insert(tail);
 
-    while offset < buffer.len() {
-        let instruction = Instruction::parse(buffer, &mut offset);
-        match instruction.direction {
-            Direction::LEFT => {
-                for _ in 0..instruction.distance {
-                    head.x -= 1;
-                    if tail.x - head.x > 1 {
-                        tail.x -= 1;
-                        tail.y = head.y;
-                        visited.insert(tail);
-                    }
-                }
-            }
-            Direction::RIGHT => {
-                for _ in 0..instruction.distance {
-                    head.x += 1;
-                    if head.x - tail.x > 1 {
-                        tail.x += 1;
-                        tail.y = head.y;
-                        visited.insert(tail);
-                    }
-                }
-            }
-            Direction::DOWN => {
-                for _ in 0..instruction.distance {
-                    head.y -= 1;
-                    if tail.y - head.y > 1 {
-                        tail.y -= 1;
-                        tail.x = head.x;
-                        visited.insert(tail);
-                    }
-                }
-            }
-            Direction::UP => {
-                for _ in 0..instruction.distance {
-                    head.y += 1;
-                    if head.y - tail.y > 1 {
-                        tail.y += 1;
-                        tail.x = head.x;
-                        visited.insert(tail);
-                    }
-                }
+    while !cursor.is_at_end() {
+        let instruction = Instruction::parse(&mut cursor);
+        let step = instruction.direction.step();
+        for _ in 0..instruction.distance {
+            head += step;
+            // The tail falls in right behind the head once they are two apart along the move
+            let gap = head - tail;
+            if gap.x * step.x + gap.y * step.y > 1 {
+                tail = head - step;
+                visited.insert(tail);
             }
         }
     }
@@ -87,22 +43,17 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut rope = [Vector2D { x: 0, y: 0 }; 10];
 
     let mut visited = HashSet::<Vector2D<i16>>::with_capacity(buffer.len() / 4);
     visited.insert(Vector2D { x: 0, y: 0 });
 
-    while offset < buffer.len() {
-        let instruction = Instruction::parse(buffer, &mut offset);
+    while !cursor.is_at_end() {
+        let instruction = Instruction::parse(&mut cursor);
         for _ in 0..instruction.distance {
-            let mut shift = match instruction.direction {
-                Direction::LEFT => Vector2D { x: -1, y: 0 },
-                Direction::RIGHT => Vector2D { x: 1, y: 0 },
-                Direction::UP => Vector2D { x: 0, y: 1 },
-                Direction::DOWN => Vector2D { x: 0, y: -1 },
-            };
+            let mut shift = instruction.direction.step();
             let mut knot = 0;
             while knot < 10 {
                 rope[knot] += shift;
@@ -110,22 +61,10 @@ pub(crate) fn part2(buffer: &[u8]) -> String {
                 if knot == 9 {
                     visited.insert(rope[knot]);
                 } else {
-                    let diff_x = (rope[knot].x - rope[knot + 1].x).abs();
-                    let diff_y = (rope[knot].y - rope[knot + 1].y).abs();
-
-                    if diff_x > 1 || diff_y > 1 {
-                        match diff_x {
-                            0 => shift.x = 0,
-                            1 => shift.x = rope[knot].x - rope[knot + 1].x,
-                            2 => shift.x = (rope[knot].x - rope[knot + 1].x) / 2,
-                            _ => panic!("How is there a more than 2 difference, this shouldn't happen..")
-                        }
-                        match diff_y {
-                            0 => shift.y = 0,
-                            1 => shift.y = rope[knot].y - rope[knot + 1].y,
-                            2 => shift.y = (rope[knot].y - rope[knot + 1].y) / 2,
-                            _ => panic!("How is there a more than 2 difference, this shouldn't happen..")
-                        }
+                    // The next knot only follows once it is no longer touching this one
+                    let gap = rope[knot] - rope[knot + 1];
+                    if gap.chebyshev_length() > 1 {
+                        shift = gap.signum();
                     } else {
                         break;
                     }
@@ -1,21 +1,21 @@
-use crate::parser::read_signed_int;
+use aoc_core::parser::Cursor;
 
-fn cpu_tick(buffer: &[u8], offset: &mut usize) -> isize {
-    match buffer[*offset] as char {
-        'a' | 'n' => {
-            *offset += 5;
+fn cpu_tick(cursor: &mut Cursor) -> isize {
+    match cursor.peek() {
+        Some(b'a' | b'n') => {
+            cursor.skip(5);
             0
         }
         _ => {
-            let amount = read_signed_int(buffer, offset).unwrap();
-            *offset += 1;
+            let amount = cursor.read_signed_int().unwrap();
+            cursor.skip(1);
             amount
         }
     }
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut x: isize = 1;
     let mut tick: isize = 1;
@@ -23,7 +23,7 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
     let mut signal_sum = 0;
 
     while tick < 220 {
-        x += cpu_tick(buffer, &mut offset);
+        x += cpu_tick(&mut cursor);
         tick += 1;
         if (tick + 20) % 40 == 0 {
             signal_sum += tick * x;
@@ -34,7 +34,7 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
 }
 
 pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
 
     let mut x: isize = 1;
     let mut tick: isize = 0;
@@ -52,7 +52,7 @@ pub(crate) fn part2(buffer: &[u8]) -> String {
         };
         display.push(pixel);
 
-        x += cpu_tick(buffer, &mut offset);
+        x += cpu_tick(&mut cursor);
         tick += 1;
     }
 
@@ -1,7 +1,7 @@
 use std::collections::{HashMap};
-use crate::array_utils::{product_n, top_n};
+use aoc_core::array_utils::{product_n, top_n};
 use crate::days::day11::Operation::{PLUS, TIMES};
-use crate::parser::read_unsigned_int;
+use aoc_core::parser::{Cursor, Expected};
 
 const MAX_HAND_SIZE: usize = 24;
 const AMOUNT_MONKEYS: usize = 8;
@@ -12,13 +12,13 @@ enum Term {
 }
 
 impl Term {
-    fn parse(buffer: &[u8], offset: &mut usize) -> Self {
-        match buffer[*offset] as char {
-            'o' => {
-                *offset += 3;
+    fn parse(cursor: &mut Cursor) -> Self {
+        match cursor.peek() {
+            Some(b'o') => {
+                cursor.skip(3);
                 Term::OLD
             },
-            _ => Term::NUMBER(read_unsigned_int(buffer, offset).unwrap() as usize),
+            _ => Term::NUMBER(cursor.read_unsigned_int().unwrap()),
         }
     }
 
@@ -35,16 +35,18 @@ enum Operation {
 }
 
 impl Operation {
-    fn parse(buffer: &[u8], offset: &mut usize) -> Self {
-        let first_term = Term::parse(buffer, offset);
-        *offset += 1;
-        let operator = buffer[*offset] as char;
-        *offset += 2;
-        let second_term = Term::parse(buffer, offset);
+    fn parse(cursor: &mut Cursor) -> Self {
+        let first_term = Term::parse(cursor);
+        cursor.skip(1);
+        let operator = cursor.peek();
+        if !matches!(operator, Some(b'*' | b'+')) {
+            panic!("{}", cursor.error(Expected::OneOf(b"*+")));
+        }
+        cursor.skip(2);
+        let second_term = Term::parse(cursor);
         match operator {
-            '*' => TIMES(first_term, second_term),
-            '+' => PLUS(first_term, second_term),
-            _ => panic!("Unknown operator: {}", operator),
+            Some(b'*') => TIMES(first_term, second_term),
+            _ => PLUS(first_term, second_term),
         }
     }
 
@@ -73,7 +75,8 @@ impl Default for Monkey {
             inventory: [None; MAX_HAND_SIZE],
             inventory_size: 0,
             operation: PLUS(Term::OLD, Term::OLD),
-            test: 0,
+            // Leaves the product of the tests unchanged when there are fewer monkeys.
+            test: 1,
             test_outcome_true: 0,
             test_outcome_false: 0,
         }
@@ -81,24 +84,24 @@ impl Default for Monkey {
 }
 
 impl Monkey {
-    fn parse(&mut self, buffer: &[u8], offset: &mut usize) {
-        *offset += 7;
-        read_unsigned_int(buffer, offset).expect("Was expecting the monkey index");
-        *offset += 18;
-        while buffer[*offset] != '\n' as u8 {
-            *offset += 2;
-            self.inventory[self.inventory_size] = Some(read_unsigned_int(buffer, offset).unwrap() as usize);
+    fn parse(&mut self, cursor: &mut Cursor) {
+        cursor.skip(7);
+        cursor.read_unsigned_int().expect("Was expecting the monkey index");
+        cursor.skip(18);
+        while cursor.peek().is_some_and(|byte| byte != b'\n') {
+            cursor.skip(2);
+            self.inventory[self.inventory_size] = Some(cursor.read_unsigned_int().unwrap());
             self.inventory_size += 1;
         }
-        *offset += 20;
-        self.operation = Operation::parse(buffer, offset);
-        *offset += 22;
-        self.test = read_unsigned_int(buffer, offset).unwrap() as usize;
-        *offset += 30;
-        self.test_outcome_true = read_unsigned_int(buffer, offset).unwrap();
-        *offset += 31;
-        self.test_outcome_false = read_unsigned_int(buffer, offset).unwrap();
-        *offset += 2;
+        cursor.skip(20);
+        self.operation = Operation::parse(cursor);
+        cursor.skip(22);
+        self.test = cursor.read_unsigned_int().unwrap();
+        cursor.skip(30);
+        self.test_outcome_true = cursor.read_unsigned_int().unwrap();
+        cursor.skip(31);
+        self.test_outcome_false = cursor.read_unsigned_int().unwrap();
+        cursor.skip(2);
     }
 
     fn consider_throw(&self, worry_level: usize) -> usize {
@@ -110,11 +113,11 @@ impl Monkey {
 }
 
 fn parse_monkeys(buffer: &[u8]) -> [Monkey; AMOUNT_MONKEYS] {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
     let mut monkeys: [Monkey; AMOUNT_MONKEYS] = Default::default();
     let mut monkey_index = 0;
-    while offset < buffer.len() {
-        monkeys[monkey_index].parse(buffer, &mut offset);
+    while !cursor.is_at_end() {
+        monkeys[monkey_index].parse(&mut cursor);
         monkeys[monkey_index].index = monkey_index;
         monkey_index += 1;
     }
@@ -130,7 +133,7 @@ fn simulate(monkeys: &mut [Monkey; AMOUNT_MONKEYS], rounds: usize, tensed: bool)
         }
     }
 
-    let mut total_inspections = [0 as usize; AMOUNT_MONKEYS];
+    let mut total_inspections = [0_usize; AMOUNT_MONKEYS];
     for _ in 0..rounds {
         for monkey_index in 0..AMOUNT_MONKEYS {
             for considering_item in 0..monkeys[monkey_index].inventory_size {
@@ -162,7 +165,7 @@ fn simulate_alternative(monkeys: &mut [Monkey; AMOUNT_MONKEYS], rounds: usize, t
         }
     }
 
-    let mut total_inspections = [0 as usize; AMOUNT_MONKEYS];
+    let mut total_inspections = [0_usize; AMOUNT_MONKEYS];
     for m_i in 0..AMOUNT_MONKEYS {
         for item in monkeys[m_i].inventory {
             if item.is_none() {
@@ -202,7 +205,7 @@ fn simulate_alternative_2(monkeys: &mut [Monkey; AMOUNT_MONKEYS], rounds: usize,
 
     let mut visited = HashMap::new();
     let mut visits = Vec::new();
-    let mut total_inspections = [0 as usize; AMOUNT_MONKEYS];
+    let mut total_inspections = [0_usize; AMOUNT_MONKEYS];
 
     for m_i in 0..AMOUNT_MONKEYS {
         'next_item:
@@ -240,8 +243,7 @@ fn simulate_alternative_2(monkeys: &mut [Monkey; AMOUNT_MONKEYS], rounds: usize,
                 }
                 round += 1;
                 let prev_visit = visited.insert((monkey_index, worry_level), round);
-                if prev_visit.is_some() {
-                    let prev_visit_index = prev_visit.unwrap();
+                if let Some(prev_visit_index) = prev_visit {
                     let circle_size = round - prev_visit_index;
                     let circling_rounds = rounds - prev_visit_index;
                     let amount_circles = circling_rounds / circle_size;
@@ -1,53 +1,15 @@
 use std::alloc::{alloc, dealloc, Layout};
-use std::cmp::Ordering;
 use std::collections::HashSet;
-use std::collections::binary_heap::BinaryHeap;
 use std::marker::PhantomData;
 use std::ptr::{NonNull, read, write};
+use aoc_core::trace;
+use aoc_core::search::{DenseVisited, Frontier, Heap, Node, Order, Search, SearchState, Visited};
 
 const MAP_HEIGHT: usize = 41;
 const MAP_WIDTH: usize = 179;
 const MAP_AREA: usize = MAP_WIDTH * MAP_HEIGHT;
 
-trait Visited {
-    fn visit(&mut self, position: usize) -> bool;
-    fn is_visited(&self, position: &usize) -> bool;
-}
-
-struct HashVisitedState {
-    state: HashSet<usize>,
-}
-
-impl Visited for HashVisitedState {
-    fn visit(&mut self, position: usize) -> bool {
-        self.state.insert(position)
-    }
-
-    fn is_visited(&self, position: &usize) -> bool {
-        self.state.contains(position)
-    }
-}
-
-struct StackVisitedState {
-    state: [bool; MAP_AREA],
-}
-
-impl Visited for StackVisitedState {
-    fn visit(&mut self, position: usize) -> bool {
-        if self.state[position] {
-            return false;
-        }
-
-        self.state[position] = true;
-        return true;
-    }
-
-    fn is_visited(&self, position: &usize) -> bool {
-        self.state[*position]
-    }
-}
-
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 struct Coordinate {
     top_neighbor: bool,
     left_neighbor: bool,
@@ -111,19 +73,20 @@ trait Map {
         return (start, end);
     }
 
-    fn draw(&self, visited: &HashSet<usize>) {
+    /// Draws the heights to stderr, the highlighted squares in green.
+    fn draw(&self, highlighted: &HashSet<usize>) {
         for offset in 0..MAP_AREA {
             if offset % MAP_WIDTH == 0 {
-                println!()
+                eprintln!()
             }
-            if visited.contains(&offset) {
-                print!("\x1b[92m");
+            if highlighted.contains(&offset) {
+                eprint!("\x1b[92m");
             } else {
-                print!("\x1b[0m");
+                eprint!("\x1b[0m");
             }
-            print!("{}", self.get_coordinate(offset).height as char);
+            eprint!("{}", self.get_coordinate(offset).height as char);
         }
-        println!()
+        eprintln!("\x1b[0m")
     }
 }
 
@@ -161,13 +124,13 @@ impl MemMap {
 impl Map for MemMap {
     fn set_height(&mut self, offset: usize, coordinate: Coordinate) {
         unsafe {
-            write(self.ptr.as_ptr().offset(offset as isize), coordinate);
+            write(self.ptr.as_ptr().add(offset), coordinate);
         }
     }
 
     fn get_coordinate(&self, offset: usize) -> Coordinate {
         unsafe {
-            read(self.ptr.as_ptr().offset(offset as isize))
+            read(self.ptr.as_ptr().add(offset))
         }
     }
 }
@@ -204,217 +167,277 @@ fn distance(pos: usize, goal: usize) -> usize {
     (pos / MAP_WIDTH).abs_diff(goal / MAP_WIDTH) + (pos % MAP_WIDTH).abs_diff(goal % MAP_WIDTH)
 }
 
-#[derive(Eq, Copy, Clone)]
-struct Solution {
-    moves: usize,
-    pos: usize,
-    distance: usize,
+#[derive(Copy, Clone)]
+enum Goal {
+    Square(usize),
+    /// Any square at height `a`, all of them in the first column like in the puzzle inputs.
+    Lowest,
 }
 
-impl Solution {
-    fn weight(&self) -> usize {
-        (usize::MAX / 2) - (self.moves + self.distance)
-    }
+/// What the states of a search share, out of them to keep the frontier small.
+struct Terrain<'a, M> {
+    map: &'a M,
+    goal: Goal,
 }
 
-impl Ord for Solution {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.weight().cmp(&other.weight())
-    }
+/// A walk down from the end, the reverse of a climb, so that part 2 can stop at the first
+/// square at height `a`.
+struct Descent<'a, M> {
+    terrain: &'a Terrain<'a, M>,
+    pos: usize,
 }
 
-impl PartialOrd<Self> for Solution {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.weight().partial_cmp(&other.weight())
+impl<M> Clone for Descent<'_, M> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
-impl PartialEq<Self> for Solution {
-    fn eq(&self, other: &Self) -> bool {
-        self.weight().eq(&other.weight())
-    }
-}
+impl<M> Copy for Descent<'_, M> {}
 
-trait SolutionSorter {
-    fn push(&mut self, solution: Solution);
-    fn pop(&mut self) -> Option<Solution>;
-}
+impl<M: Map> SearchState for Descent<'_, M> {
+    type Key = usize;
 
-struct BinaryHeapSorter {
-    solutions: BinaryHeap<Solution>,
-}
+    fn key(&self) -> Self::Key {
+        self.pos
+    }
+
+    fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
+        let coordinate = self.terrain.map.get_coordinate(self.pos);
+        if coordinate.top_neighbor {
+            neighbours.push((Descent { pos: self.pos - MAP_WIDTH, ..*self }, 1));
+        }
+        if coordinate.bottom_neighbor {
+            neighbours.push((Descent { pos: self.pos + MAP_WIDTH, ..*self }, 1));
+        }
+        if coordinate.left_neighbor {
+            neighbours.push((Descent { pos: self.pos - 1, ..*self }, 1));
+        }
+        if coordinate.right_neighbor {
+            neighbours.push((Descent { pos: self.pos + 1, ..*self }, 1));
+        }
+    }
 
-impl SolutionSorter for BinaryHeapSorter {
-    fn push(&mut self, solution: Solution) {
-        self.solutions.push(solution);
+    fn is_goal(&self) -> bool {
+        match self.terrain.goal {
+            Goal::Square(goal) => self.pos == goal,
+            Goal::Lowest => self.terrain.map.get_coordinate(self.pos).height == b'a',
+        }
     }
 
-    fn pop(&mut self) -> Option<Solution> {
-        self.solutions.pop()
+    fn heuristic(&self) -> usize {
+        match self.terrain.goal {
+            Goal::Square(goal) => distance(self.pos, goal),
+            Goal::Lowest => self.pos % MAP_WIDTH,
+        }
     }
 }
 
-struct VectorSorter {
-    solutions: [Vec<Solution>; 3],
+/// Buckets for three priorities in a ring, enough for A* on a map where each step changes the
+/// estimate by two at most.
+struct VectorSorter<T> {
+    solutions: [Vec<T>; 3],
     cursor: usize,
-    weight_at_cursor: usize,
+    priority_at_cursor: usize,
+    len: usize,
+}
+
+impl<T> VectorSorter<T> {
+    fn new() -> Self {
+        VectorSorter {
+            solutions: [Vec::new(), Vec::new(), Vec::new()],
+            cursor: 0,
+            priority_at_cursor: 0,
+            len: 0,
+        }
+    }
 }
 
-impl SolutionSorter for VectorSorter {
-    fn push(&mut self, solution: Solution) {
-        let weight = solution.weight();
-        let over_weight = self.weight_at_cursor.abs_diff(weight);
-        if over_weight >= 3 {
+impl<T> Frontier<T> for VectorSorter<T> {
+    fn push(&mut self, priority: usize, solution: T) {
+        self.len += 1;
+        if priority < self.priority_at_cursor && self.priority_at_cursor - priority < 3 {
+            // Only the neighbors of the start can come before the first solution pushed
+            self.cursor = (self.cursor + 3 - (self.priority_at_cursor - priority)) % 3;
+            self.priority_at_cursor = priority;
+        }
+
+        let over_priority = self.priority_at_cursor.abs_diff(priority);
+        if over_priority >= 3 {
             self.solutions[self.cursor].push(solution);
-            self.weight_at_cursor = weight;
+            self.priority_at_cursor = priority;
             return;
         }
 
-        self.solutions[(self.cursor + over_weight) % 3].push(solution);
+        self.solutions[(self.cursor + over_priority) % 3].push(solution);
     }
 
-    fn pop(&mut self) -> Option<Solution> {
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
         while self.solutions[self.cursor].is_empty() {
             self.cursor = (self.cursor + 1) % 3;
-            self.weight_at_cursor -= 1;
+            self.priority_at_cursor += 1;
         }
 
+        self.len -= 1;
         self.solutions[self.cursor].pop()
     }
-}
-
-fn part1_algo(buffer: &[u8], map: &mut dyn Map, visited: &mut dyn Visited, sorter: &mut dyn SolutionSorter) -> String {
-    let (end, start) = map.build_from_buffer(buffer);
-
-    visited.visit(start);
 
-    let mut considering = Some(Solution {
-        moves: 0,
-        pos: start,
-        distance: distance(start, end),
-    });
-
-    while considering.is_some() {
-        let current = considering.unwrap();
-        if current.pos == end {
-            break;
-        }
-
-        let coordinate = map.get_coordinate(current.pos);
-        if coordinate.top_neighbor {
-            let neighbor_pos = current.pos - MAP_WIDTH;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: distance(neighbor_pos, end) })
-            }
-        }
-        if coordinate.bottom_neighbor {
-            let neighbor_pos = current.pos + MAP_WIDTH;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: distance(neighbor_pos, end) })
-            }
-        }
-        if coordinate.left_neighbor {
-            let neighbor_pos = current.pos - 1;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: distance(neighbor_pos, end) })
-            }
-        }
-        if coordinate.right_neighbor {
-            let neighbor_pos = current.pos + 1;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: distance(neighbor_pos, end) })
-            }
-        }
+    fn len(&self) -> usize {
+        self.len
+    }
 
-        considering = sorter.pop();
+    fn clear(&mut self) {
+        *self = VectorSorter::new();
     }
+}
 
-    // map.draw(&visited);
-    return considering.unwrap().moves.to_string()
+fn fewest_steps<'a, M, F, V>(start: Descent<'a, M>, search: Search<F, V>) -> String
+where
+    M: Map,
+    F: Frontier<Node<Descent<'a, M>>>,
+    V: Visited<usize>,
+{
+    // The path is only needed to draw it
+    let found = search.keep_path(trace::enabled()).run(start).expect("The goal is out of reach");
+    if trace::enabled() {
+        start.terrain.map.draw(&found.path.iter().map(|descent| descent.pos).collect());
+        trace!("{}", found.stats);
+    }
+    return found.cost.to_string();
 }
 
 pub(crate) fn part1_old(buffer: &[u8]) -> String {
     let mut map = MemMap::new();
-    let mut visited = HashVisitedState { state: HashSet::new() };
-    let mut sorter = BinaryHeapSorter { solutions: BinaryHeap::new() };
-    return part1_algo(buffer, &mut map, &mut visited, &mut sorter);
+    let (start, end) = map.build_from_buffer(buffer);
+    let terrain = Terrain { map: &map, goal: Goal::Square(start) };
+    return fewest_steps(Descent { terrain: &terrain, pos: end }, Search::new(Order::Estimate, Heap::new(), HashSet::new()));
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
     let mut map = StackMap::new();
-    let mut visited = StackVisitedState { state: [false; MAP_AREA] };
-    let mut sorter = VectorSorter {
-        solutions: [Vec::new(), Vec::new(), Vec::new()],
-        cursor: 0,
-        weight_at_cursor: 0,
-    };
-    return part1_algo(buffer, &mut map, &mut visited, &mut sorter);
+    let (start, end) = map.build_from_buffer(buffer);
+    let terrain = Terrain { map: &map, goal: Goal::Square(start) };
+    return fewest_steps(Descent { terrain: &terrain, pos: end }, Search::new(Order::Estimate, VectorSorter::new(), DenseVisited::new(MAP_AREA)));
 }
 
-fn part2_algo(buffer: &[u8], map: &mut dyn Map, visited: &mut dyn Visited, sorter: &mut dyn SolutionSorter) -> String {
+pub(crate) fn part2_old(buffer: &[u8]) -> String {
+    let mut map = MemMap::new();
     let (_, end) = map.build_from_buffer(buffer);
+    let terrain = Terrain { map: &map, goal: Goal::Lowest };
+    return fewest_steps(Descent { terrain: &terrain, pos: end }, Search::new(Order::Estimate, Heap::new(), HashSet::new()));
+}
 
-    visited.visit(end);
-
-    let mut considering = Some(Solution {
-        moves: 0,
-        pos: end,
-        distance: 0,
-    });
+pub(crate) fn part2(buffer: &[u8]) -> String {
+    let mut map = StackMap::new();
+    let (_, end) = map.build_from_buffer(buffer);
+    let terrain = Terrain { map: &map, goal: Goal::Lowest };
+    return fewest_steps(Descent { terrain: &terrain, pos: end }, Search::new(Order::Estimate, VectorSorter::new(), DenseVisited::new(MAP_AREA)));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use aoc_core::grid::Grid;
+    use proptest::prelude::*;
+    use super::*;
+
+    /// A heightmap rising from `a` on the left to `z` on the right, with some noise so that
+    /// not every step can be climbed. Like in the puzzle inputs, which the part 2 heuristic
+    /// relies on, only the first column is at height `a`.
+    fn heightmap() -> impl Strategy<Value = Vec<u8>> {
+        let noise = prop::collection::vec(0..=2u8, MAP_AREA);
+        return (noise, 0..MAP_HEIGHT, 0..MAP_HEIGHT).prop_map(|(noise, start, end)| {
+            let mut buffer = Vec::with_capacity(MAP_AREA + MAP_HEIGHT);
+            for y in 0..MAP_HEIGHT {
+                for x in 0..MAP_WIDTH {
+                    let height = 1 + (x * 24 / (MAP_WIDTH - 1)) as u8 + noise[y * MAP_WIDTH + x];
+                    buffer.push(match (x, y) {
+                        (0, y) if y == start => b'S',
+                        (0, _) => b'a',
+                        (x, y) if x == MAP_WIDTH - 1 && y == end => b'E',
+                        _ => b'a' + height.min(25),
+                    });
+                }
+                buffer.push(b'\n');
+            }
+            return buffer;
+        });
+    }
 
-    while considering.is_some() {
-        let current = considering.unwrap();
-        let coordinate = map.get_coordinate(current.pos);
-        if coordinate.height == 'a' as u8 {
-            break;
+    /// Fewest steps from any of the squares matching `is_start` to the end, climbing at most one.
+    fn shortest_path(buffer: &[u8], is_start: impl Fn(u8) -> bool) -> Option<usize> {
+        let map = Grid::parse(buffer, |square| square).unwrap();
+        let mut steps: Grid<Option<usize>> = Grid::new(map.size(), None);
+        let mut queue = VecDeque::new();
+        for position in map.positions() {
+            if is_start(map[position]) {
+                steps[position] = Some(0);
+                queue.push_back(position);
+            }
         }
 
-        if coordinate.top_neighbor {
-            let neighbor_pos = current.pos - MAP_WIDTH;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: neighbor_pos % MAP_WIDTH })
+        while let Some(position) = queue.pop_front() {
+            if map[position] == b'E' {
+                return steps[position];
             }
-        }
-        if coordinate.bottom_neighbor {
-            let neighbor_pos = current.pos + MAP_WIDTH;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: neighbor_pos % MAP_WIDTH })
+
+            for neighbor in map.neighbours4(position) {
+                if steps[neighbor].is_none() && sanitize_height(map[neighbor]) <= sanitize_height(map[position]) + 1 {
+                    steps[neighbor] = Some(steps[position].unwrap() + 1);
+                    queue.push_back(neighbor);
+                }
             }
         }
-        if coordinate.left_neighbor {
-            let neighbor_pos = current.pos - 1;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: neighbor_pos % MAP_WIDTH })
+        return None;
+    }
+
+    proptest! {
+        #[test]
+        fn maps_agree(buffer in heightmap()) {
+            let mut mem_map = MemMap::new();
+            let mut stack_map = Box::new(StackMap::new());
+            prop_assert_eq!(mem_map.build_from_buffer(&buffer), stack_map.build_from_buffer(&buffer));
+            for offset in 0..MAP_AREA {
+                prop_assert_eq!(mem_map.get_coordinate(offset), stack_map.get_coordinate(offset));
             }
         }
-        if coordinate.right_neighbor {
-            let neighbor_pos = current.pos + 1;
-            if visited.visit(neighbor_pos) {
-                sorter.push(Solution { moves: current.moves + 1, pos: neighbor_pos, distance: neighbor_pos % MAP_WIDTH })
+
+        /// Drives the sorters like the search does: every priority pushed is at most two more
+        /// than the last one popped.
+        #[test]
+        fn sorters_agree(steps in prop::collection::vec(prop::collection::vec(0..=2usize, 0..4), 1..256)) {
+            let mut heap = Heap::new();
+            let mut vector = VectorSorter::new();
+            let mut priority = distance(0, MAP_AREA - 1);
+            for higher_by in steps {
+                for higher_by in higher_by {
+                    heap.push(priority + higher_by, priority + higher_by);
+                    vector.push(priority + higher_by, priority + higher_by);
+                }
+                prop_assert_eq!(vector.len(), heap.len());
+                if heap.is_empty() {
+                    break;
+                }
+
+                priority = heap.pop().unwrap();
+                prop_assert_eq!(vector.pop(), Some(priority));
             }
         }
 
-        considering = sorter.pop();
+        #[test]
+        fn variants_agree(buffer in heightmap()) {
+            let fewest_steps = shortest_path(&buffer, |square| square == b'S');
+            prop_assume!(fewest_steps.is_some());
+            let fewest_steps = fewest_steps.unwrap().to_string();
+            prop_assert_eq!(part1(&buffer), fewest_steps.clone());
+            prop_assert_eq!(part1_old(&buffer), fewest_steps);
+
+            let fewest_steps = shortest_path(&buffer, |square| sanitize_height(square) == b'a').unwrap().to_string();
+            prop_assert_eq!(part2(&buffer), fewest_steps.clone());
+            prop_assert_eq!(part2_old(&buffer), fewest_steps);
+        }
     }
-
-    // map.draw(&visited);
-    return considering.unwrap().moves.to_string()
-}
-
-pub(crate) fn part2_old(buffer: &[u8]) -> String {
-    let mut map = MemMap::new();
-    let mut visited = HashVisitedState { state: HashSet::new() };
-    let mut sorter = BinaryHeapSorter { solutions: BinaryHeap::new() };
-    return part2_algo(buffer, &mut map, &mut visited, &mut sorter);
-}
-
-pub(crate) fn part2(buffer: &[u8]) -> String {
-    let mut map = StackMap::new();
-    let mut visited = StackVisitedState { state: [false; MAP_AREA] };
-    let mut sorter = VectorSorter {
-        solutions: [Vec::new(), Vec::new(), Vec::new()],
-        cursor: 0,
-        weight_at_cursor: 0,
-    };
-    return part2_algo(buffer, &mut map, &mut visited, &mut sorter);
 }
@@ -1,76 +1,73 @@
 use std::cmp::Ordering;
 use std::collections::binary_heap::BinaryHeap;
-use crate::parser::{move_to_next_line, read_unsigned_int};
+use aoc_core::parser::Cursor;
 
 
-pub(crate) fn compare_elements(left_buff: &[u8],
-                               left_offset: &mut usize,
-                               right_buff: &[u8],
-                               right_offset: &mut usize) -> Option<bool> {
-    let left_array = left_buff[*left_offset] == '[' as u8;
-    let right_array = right_buff[*right_offset] == '[' as u8;
+pub(crate) fn compare_elements(left: &mut Cursor, right: &mut Cursor) -> Option<bool> {
+    let left_array = left.peek() == Some(b'[');
+    let right_array = right.peek() == Some(b'[');
 
     if left_array && right_array {
         loop {
-            *left_offset += 1;
-            *right_offset += 1;
+            left.skip(1);
+            right.skip(1);
 
-            if left_buff[*left_offset] == ']' as u8 || right_buff[*right_offset] == ']' as u8 {
+            if left.peek() == Some(b']') || right.peek() == Some(b']') {
                 break;
             }
 
-            let comparison = compare_elements(left_buff, left_offset, right_buff, right_offset);
+            let comparison = compare_elements(left, right);
             if comparison.is_some() {
                 return comparison;
             }
 
-            if left_buff[*left_offset] == ']' as u8 || right_buff[*right_offset] == ']' as u8 {
+            if left.peek() == Some(b']') || right.peek() == Some(b']') {
                 break;
             }
         }
 
-        if left_buff[*left_offset] == ']' as u8 && right_buff[*right_offset] == ']' as u8 {
-            *right_offset += 1;
-            *left_offset += 1;
+        if left.peek() == Some(b']') && right.peek() == Some(b']') {
+            right.skip(1);
+            left.skip(1);
             return None;
         }
 
-        return Some(left_buff[*left_offset] == ']' as u8);
+        return Some(left.peek() == Some(b']'));
 
     } else if left_array {
-        *left_offset += 1;
-        let comparison = compare_elements(left_buff, left_offset, right_buff, right_offset);
+        left.skip(1);
+        let comparison = compare_elements(left, right);
         if comparison.is_some() {
             return comparison;
         }
-        if left_buff[*left_offset] == ']' as u8 {
-            *left_offset += 1;
+        if left.peek() == Some(b']') {
+            left.skip(1);
             return None;
         }
         return Some(false);
     } else if right_array {
-        *right_offset += 1;
-        let comparison = compare_elements(left_buff, left_offset, right_buff, right_offset);
+        right.skip(1);
+        let comparison = compare_elements(left, right);
         if comparison.is_some() {
             return comparison;
         }
-        if right_buff[*right_offset] == ']' as u8 {
-            *right_offset += 1;
+        if right.peek() == Some(b']') {
+            right.skip(1);
             return None;
         }
         return Some(true);
     }
 
-    if left_buff[*left_offset] == ']' as u8 {
+    if left.peek() == Some(b']') {
         return Some(true)
     }
 
-    if right_buff[*right_offset] == ']' as u8 {
+    if right.peek() == Some(b']') {
         return Some(false)
     }
 
-    let left_value = read_unsigned_int(left_buff, left_offset).unwrap();
-    let right_value = read_unsigned_int(right_buff, right_offset).unwrap();
+    let left_value = left.read_unsigned_int().unwrap();
+    let right_value = right.read_unsigned_int().unwrap();
 
     if left_value != right_value {
         return Some(left_value < right_value);
@@ -80,67 +77,58 @@ pub(crate) fn compare_elements(left_buff: &[u8],
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
     let mut index = 1;
     let mut valid_amount = 0;
     loop {
-        let mut left_offset = offset;
-        move_to_next_line(buffer, &mut offset);
-        let mut right_offset = offset;
-        let valid = compare_elements(buffer,
-                                     &mut left_offset,
-                                     buffer,
-                                     &mut right_offset).unwrap();
+        let mut left = cursor;
+        cursor.move_to_next_line();
+        let mut right = cursor;
+        let valid = compare_elements(&mut left, &mut right).unwrap();
         if valid {
             valid_amount += index;
         }
 
-        move_to_next_line(buffer, &mut offset);
-        if offset >= buffer.len() {
+        cursor.move_to_next_line();
+        if cursor.is_at_end() {
             break;
         }
 
-        move_to_next_line(buffer, &mut offset);
+        cursor.move_to_next_line();
         index += 1;
     }
     return valid_amount.to_string()
 }
 
-struct Packet {
-    buffer:  *const [u8],
-    offset: usize,
+struct Packet<'a> {
+    cursor: Cursor<'a>,
 }
 
-impl Eq for Packet  {}
+impl Eq for Packet<'_> {}
 
-impl PartialEq<Self> for Packet {
+impl PartialEq<Self> for Packet<'_> {
     fn eq(&self, other: &Self) -> bool {
         return self.cmp(other) == Ordering::Equal
     }
 }
 
-impl PartialOrd<Self> for Packet {
+impl PartialOrd<Self> for Packet<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Packet {
+impl Ord for Packet<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
-        unsafe {
-            let mut self_offset = self.offset;
-            let mut other_offset = other.offset;
-            return match compare_elements(self.buffer.as_ref().unwrap(),
-                                          &mut self_offset,
-                                          other.buffer.as_ref().unwrap(),
-                                          &mut other_offset) {
-                None => Ordering::Equal,
-                Some(order) => match order {
-                    true => Ordering::Greater,
-                    false => Ordering::Less,
-                }
-            };
-        }
+        let mut self_cursor = self.cursor;
+        let mut other_cursor = other.cursor;
+        return match compare_elements(&mut self_cursor, &mut other_cursor) {
+            None => Ordering::Equal,
+            Some(order) => match order {
+                true => Ordering::Greater,
+                false => Ordering::Less,
+            }
+        };
     }
 }
 
@@ -151,32 +139,32 @@ pub(crate) fn part2(buffer: &[u8]) -> String {
     let mut index_2 = 1;
     let mut index_6 = 2;
 
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
     loop {
-        let mut o1 = offset;
-        let mut o2 = offset;
-        if compare_elements(buffer, &mut o1, additional_two, &mut 0).unwrap() {
+        let mut o1 = cursor;
+        let mut o2 = cursor;
+        if compare_elements(&mut o1, &mut Cursor::new(additional_two)).unwrap() {
             index_6 += 1;
             index_2 += 1;
-        } else if compare_elements(buffer, &mut o2, additional_six, &mut 0).unwrap() {
+        } else if compare_elements(&mut o2, &mut Cursor::new(additional_six)).unwrap() {
             index_6 += 1;
         }
-        move_to_next_line(buffer, &mut offset);
-        o1 = offset;
-        o2 = offset;
-        if compare_elements(buffer, &mut o1, additional_two, &mut 0).unwrap() {
+        cursor.move_to_next_line();
+        o1 = cursor;
+        o2 = cursor;
+        if compare_elements(&mut o1, &mut Cursor::new(additional_two)).unwrap() {
             index_6 += 1;
             index_2 += 1;
-        } else if compare_elements(buffer, &mut o2, additional_six, &mut 0).unwrap() {
+        } else if compare_elements(&mut o2, &mut Cursor::new(additional_six)).unwrap() {
             index_6 += 1;
         }
-        move_to_next_line(buffer, &mut offset);
+        cursor.move_to_next_line();
 
-        if offset >= buffer.len() {
+        if cursor.is_at_end() {
             break;
         }
 
-        move_to_next_line(buffer, &mut offset);
+        cursor.move_to_next_line();
     }
 
 
@@ -187,27 +175,21 @@ pub(crate) fn part2_old(buffer: &[u8]) -> String {
     let additional_six = "[[6]]\n".as_bytes();
 
     let mut ordered_packets = BinaryHeap::new();
-    ordered_packets.push(Packet {
-        buffer: additional_two as *const [u8],
-        offset: 0,
-    });
-    ordered_packets.push(Packet {
-        buffer: additional_six as *const [u8],
-        offset: 0,
-    });
-
-    let mut offset = 0;
+    ordered_packets.push(Packet { cursor: Cursor::new(additional_two) });
+    ordered_packets.push(Packet { cursor: Cursor::new(additional_six) });
+
+    let mut cursor = Cursor::new(buffer);
     loop {
-        ordered_packets.push(Packet { buffer: buffer as *const [u8], offset });
-        move_to_next_line(buffer, &mut offset);
-        ordered_packets.push(Packet { buffer: buffer as *const [u8], offset });
-        move_to_next_line(buffer, &mut offset);
+        ordered_packets.push(Packet { cursor });
+        cursor.move_to_next_line();
+        ordered_packets.push(Packet { cursor });
+        cursor.move_to_next_line();
 
-        if offset >= buffer.len() {
+        if cursor.is_at_end() {
             break;
         }
 
-        move_to_next_line(buffer, &mut offset);
+        cursor.move_to_next_line();
     }
 
     let mut index = 1;
@@ -215,9 +197,9 @@ pub(crate) fn part2_old(buffer: &[u8]) -> String {
 
     while !ordered_packets.is_empty() {
         let p = ordered_packets.pop().unwrap();
-        if p.buffer == additional_two  as *const [u8] {
+        if std::ptr::eq(p.cursor.buffer(), additional_two) {
             product *= index;
-        } else if p.buffer == additional_six  as *const [u8] {
+        } else if std::ptr::eq(p.cursor.buffer(), additional_six) {
             product *= index;
             break;
         }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
-use crate::parser::read_unsigned_int;
-use crate::vector::Vector2D;
+use aoc_core::parser::Cursor;
+use aoc_core::vector::Vector2D;
 
 const DOWN: Vector2D<i16> = Vector2D { x: 0, y: 1 };
 const DOWN_LEFT: Vector2D<i16> = Vector2D { x: -1, y: 1 };
@@ -19,7 +19,7 @@ struct HashSetMap {
 
 impl MapState for HashSetMap {
     fn is_solid(&self, position: &Vector2D<i16>) -> bool {
-        self.solid.contains(&position)
+        self.solid.contains(position)
     }
 
     fn set_solid(&mut self, position: Vector2D<i16>) {
@@ -51,31 +51,13 @@ impl MapState for StackMap {
     }
 }
 
-fn parse_vector2d(buffer: &[u8], offset: &mut usize) -> Vector2D<i16> {
-    let x = read_unsigned_int(buffer, offset).unwrap() as i16;
-    *offset += 1;
-    let y = read_unsigned_int(buffer, offset).unwrap() as i16;
+fn parse_vector2d(cursor: &mut Cursor) -> Vector2D<i16> {
+    let x = cursor.read_int().unwrap();
+    cursor.skip(1);
+    let y = cursor.read_int().unwrap();
     Vector2D { x, y }
 }
 
-fn unit(vector: Vector2D<i16>) -> Vector2D<i16> {
-    if vector.x == 0 {
-        if vector.y > 0 {
-            Vector2D { x: 0, y: 1 }
-        } else {
-            Vector2D { x: 0, y: -1 }
-        }
-    } else if vector.y == 0 {
-        if vector.x > 0 {
-            Vector2D { x: 1, y: 0 }
-        } else {
-            Vector2D { x: -1, y: 0 }
-        }
-    } else {
-        unimplemented!()
-    }
-}
-
 fn simulate_simple(position: Vector2D<i16>, lowest_point: i16, solid: &mut dyn MapState, stop_at_fallthrough: bool) -> (usize, bool) {
     if stop_at_fallthrough && position.y > lowest_point {
             return (0, true);
@@ -130,9 +112,7 @@ fn simulate_complex(position: Vector2D<i16>, lowest_point: i16, solid: &mut dyn
         only_right_for = new_only_right_for;
     }
     if remaining_only_right_for == 0 {
-        if only_left_for > 0 {
-            only_left_for -= 1;
-        }
+        only_left_for = only_left_for.saturating_sub(1);
         let (new_solidified, fell_through, new_only_left_for, _) = simulate_complex(position + DOWN_LEFT, lowest_point, solid, stop_at_fallthrough, only_left_for, 0);
         solidified += new_solidified;
         if fell_through {
@@ -141,9 +121,7 @@ fn simulate_complex(position: Vector2D<i16>, lowest_point: i16, solid: &mut dyn
         only_left_for = new_only_left_for + 1;
     }
     if remaining_only_left_for == 0 {
-        if only_right_for > 0 {
-            only_right_for -= 1;
-        }
+        only_right_for = only_right_for.saturating_sub(1);
         let (new_solidified, fell_through, _, new_only_right_for) = simulate_complex(position + DOWN_RIGHT, lowest_point, solid, stop_at_fallthrough, 0, only_right_for);
         solidified += new_solidified;
         if fell_through {
@@ -157,28 +135,29 @@ fn simulate_complex(position: Vector2D<i16>, lowest_point: i16, solid: &mut dyn
 }
 
 fn load_structures(buffer: &[u8], state: &mut dyn MapState) -> i16 {
-    let mut offset = 0;
+    let mut cursor = Cursor::new(buffer);
     let mut lowest_point = 0;
 
-    while offset < buffer.len() {
-        let mut from = parse_vector2d(buffer, &mut offset);
+    while !cursor.is_at_end() {
+        let mut from = parse_vector2d(&mut cursor);
         state.set_solid(from);
 
-        while buffer[offset] != '\n' as u8 {
-            offset += 4;
-            let to = parse_vector2d(buffer, &mut offset);
+        while cursor.peek().is_some_and(|byte| byte != b'\n') {
+            cursor.skip(4);
+            let to = parse_vector2d(&mut cursor);
             if to.y > lowest_point {
                 lowest_point = to.y;
             }
 
-            let step = unit(to - from);
+            let step = (to - from).signum();
+            assert!(step.x == 0 || step.y == 0, "The paths only run along the axes");
 
             while from != to {
                 from += step;
                 state.set_solid(from);
             }
         }
-        offset += 1;
+        cursor.skip(1);
     }
     return lowest_point;
 }
@@ -223,4 +202,63 @@ pub(crate) fn part2(buffer: &[u8]) -> String {
     let lowest_point = load_structures(buffer, &mut state);
     let (solidified, _, _, _) = simulate_complex(SAND_SOURCE, lowest_point, &mut state, false, 0, 0);
     return solidified.to_string()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    fn position() -> impl Strategy<Value = Vector2D<i16>> {
+        let max_x = (MIN_X + AMOUNT_WIDTH_SECTIONS * 64) as i16;
+        return (MIN_X as i16..max_x, 0..MAX_HEIGHT as i16).prop_map(|(x, y)| Vector2D { x, y });
+    }
+
+    /// Rock paths under the sand source, each turning from horizontal to vertical at every point.
+    fn scan() -> impl Strategy<Value = String> {
+        let path = (470..530i16, 1..30i16, prop::collection::vec((470..530i16, 1..30i16), 1..6));
+        return prop::collection::vec(path, 1..12).prop_map(|paths| {
+            let mut scan = String::new();
+            for (x, y, turns) in paths {
+                let mut points = vec![Vector2D { x, y }];
+                for (index, (x, y)) in turns.into_iter().enumerate() {
+                    let last = *points.last().unwrap();
+                    points.push(match index % 2 {
+                        0 => Vector2D { x, y: last.y },
+                        _ => Vector2D { x: last.x, y },
+                    });
+                }
+                let points: Vec<String> = points.iter().map(|point| format!("{},{}", point.x, point.y)).collect();
+                scan.push_str(&format!("{}\n", points.join(" -> ")));
+            }
+            return scan;
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn map_states_agree(operations in prop::collection::vec((any::<bool>(), position()), 0..512)) {
+            let mut hash_set = HashSetMap { solid: HashSet::new() };
+            let mut stack = StackMap { solid: [[0; MAX_HEIGHT]; AMOUNT_WIDTH_SECTIONS] };
+            for (set, position) in operations {
+                if set {
+                    hash_set.set_solid(position);
+                    stack.set_solid(position);
+                }
+                prop_assert_eq!(hash_set.is_solid(&position), stack.is_solid(&position));
+            }
+        }
+
+        #[test]
+        fn variants_agree(scan in scan()) {
+            let buffer = scan.as_bytes();
+            let resting = part1_simple(buffer);
+            prop_assert_eq!(part1_complex_hashset(buffer), resting.clone());
+            prop_assert_eq!(part1(buffer), resting);
+
+            let resting = part2_simple(buffer);
+            prop_assert_eq!(part2_complex_hashset(buffer), resting.clone());
+            prop_assert_eq!(part2(buffer), resting);
+        }
+    }
+}
@@ -1,18 +1,11 @@
 use std::fmt::{Display, Formatter};
 
-use crate::{vector::Vector2D, parser::read_signed_int};
-
-fn parse_line(buffer: &[u8], cursor: &mut usize) -> (Vector2D<i32>, Vector2D<i32>) {
-    *cursor += 12;
-    let sensor_x = read_signed_int(buffer, cursor).unwrap();
-    *cursor += 4;
-    let sensor_y = read_signed_int(buffer, cursor).unwrap();
-    *cursor += 25;
-    let beacon_x = read_signed_int(buffer, cursor).unwrap();
-    *cursor += 4;
-    let beacon_y = read_signed_int(buffer, cursor).unwrap();
-    *cursor += 1;
-    return (Vector2D{x: sensor_x as i32, y: sensor_y as i32}, Vector2D{x: beacon_x as i32, y: beacon_y as i32})
+use aoc_core::{parse, vector::Vector2D, parser::Cursor, range_set::RangeSet};
+
+fn parse_line(cursor: &mut Cursor) -> (Vector2D<i32>, Vector2D<i32>) {
+    let (sensor_x, sensor_y, beacon_x, beacon_y) =
+        parse!(cursor, "Sensor at x=" {i32} ", y=" {i32} ": closest beacon is at x=" {i32} ", y=" {i32} "\n").unwrap();
+    return (Vector2D{x: sensor_x, y: sensor_y}, Vector2D{x: beacon_x, y: beacon_y})
 }
 
 pub(crate) fn part1(buffer: &[u8]) -> String {
@@ -22,9 +15,9 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
     let mut beacons_on_row = [0; 10];
     let mut beacons_on_row_amount = 0;
 
-    let mut cursor = 0;
-    while cursor < buffer.len() {
-        let (sensor, beacon) = parse_line(buffer, &mut cursor);
+    let mut cursor = Cursor::new(buffer);
+    while !cursor.is_at_end() {
+        let (sensor, beacon) = parse_line(&mut cursor);
         if beacon.y == SCANNED_Y {
             let mut already_detected = false;
             for i in 0..beacons_on_row_amount {
@@ -99,6 +92,28 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
     return viewing_units.to_string()
 }
 
+pub(crate) fn part1_range_set(buffer: &[u8]) -> String {
+    const SCANNED_Y: i32 =  2000000;
+    let mut clear = RangeSet::new();
+    let mut beacons_on_row = Vec::new();
+
+    let mut cursor = Cursor::new(buffer);
+    while !cursor.is_at_end() {
+        let (sensor, beacon) = parse_line(&mut cursor);
+        if beacon.y == SCANNED_Y {
+            beacons_on_row.push(beacon.x);
+        }
+
+        let vision_width = (sensor - beacon).manhattan_length() - (sensor.y - SCANNED_Y).abs();
+        clear.insert(sensor.x - vision_width..=sensor.x + vision_width);
+    }
+
+    for beacon in beacons_on_row {
+        clear.remove(beacon..=beacon);
+    }
+    return clear.len().to_string()
+}
+
 struct ScanArea {
     top: Vector2D<i32>,
     bottom: Vector2D<i32>,
@@ -250,7 +265,7 @@ impl ScanArea {
             return true;
         }
 
-        match self.intersection(&area) {
+        match self.intersection(area) {
             None => { 
                 return false;
             },
@@ -367,9 +382,9 @@ pub(crate) fn part2(buffer: &[u8]) -> String {
 
     let mut scan_area = ScanArea::square_with_range(2 * RANGE_MIN, 2 * RANGE_MAX);
 
-    let mut cursor = 0;
-    while cursor < buffer.len() {
-        let (sensor, beacon) = parse_line(buffer, &mut cursor);
+    let mut cursor = Cursor::new(buffer);
+    while !cursor.is_at_end() {
+        let (sensor, beacon) = parse_line(&mut cursor);
         let manhattan_length = (sensor - beacon).manhattan_length();
         let cleared_area = ScanArea {
             top: Vector2D { x: sensor.x, y: sensor.y - manhattan_length } * 2,
@@ -1,6 +1,7 @@
 use std::collections::BinaryHeap;
 
-use crate::parser::{read_unsigned_int, skip_word};
+use aoc_core::parse;
+use aoc_core::parser::Cursor;
 
 const MAX_NEIGHBORS: usize = 8;
 const ALPHABET_SIZE: usize = 26;
@@ -34,7 +35,7 @@ enum Action {
     Move(u16),
 }
 
-#[derive(Copy, Clone, Hash)]
+#[derive(Copy, Clone)]
 struct State {
     opened_valves: [u16; MAX_OPENED_VALVES],
     amount_opened_valves: u8,
@@ -58,20 +59,6 @@ impl State {
         }
         (self.released_pressure + self.pressure_per_second * self.remaining_time as u16 + potential) as i16
     }
-
-    fn nextState(n: u64) -> impl std::iter::Iterator<Item = State> {
-        let mut num = 0;
-        std::iter::from_fn(move || {
-            let result;
-            if num < n {
-                result = Some(num);
-                num += 1
-            } else {
-                result = None
-            }
-            result
-        })
-    }
 }
 
 impl PartialEq for State {
@@ -96,33 +83,18 @@ impl PartialOrd for State {
 pub(crate) fn part1(buffer: &[u8]) -> String {
     let mut neighborhood = [Valve::new(); MAX_VALVES];
 
-    let mut cursor: usize = 0;
-    while cursor < buffer.len() {
-        cursor += 6;
-        let valve_index = Valve::index_of(buffer[cursor], buffer[cursor + 1]);
-        cursor += 17;
-        neighborhood[valve_index].flow = read_unsigned_int(buffer, &mut cursor).unwrap() as u32;
-        cursor += 2;
-        skip_word(buffer, &mut cursor); // tunnel.s
-        cursor += 1;
-        skip_word(buffer, &mut cursor); // lead.s
-        cursor += 4;
-        skip_word(buffer, &mut cursor); // valve.s
-        cursor += 1;
-
-        let mut has_other_valve = true;
-        while has_other_valve {
-            let neighbor_valve_index = Valve::index_of(buffer[cursor], buffer[cursor + 1]);
+    let mut cursor = Cursor::new(buffer);
+    while !cursor.is_at_end() {
+        let (name, flow, tunnels) = parse!(&mut cursor,
+            "Valve " {id} " has flow rate=" {u32} "; tunnel" {s?} " lead" {s?} " to valve" {s?} " " {list:id} "\n").unwrap();
+        let valve_index = Valve::index_of(name[0], name[1]);
+        neighborhood[valve_index].flow = flow;
+
+        for neighbor in tunnels.iter() {
+            let neighbor_valve_index = Valve::index_of(neighbor[0], neighbor[1]);
             neighborhood[valve_index].neighbors[neighborhood[valve_index].amount_neighbors as usize] =
                 neighbor_valve_index as u16;
             neighborhood[valve_index].amount_neighbors += 1;
-
-            if buffer[cursor + 2] != ',' as u8 {
-                has_other_valve = false;
-                cursor += 3
-            } else {
-                cursor += 4;
-            }
         }
     }
 
@@ -137,13 +109,11 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
         remaining_time: MAX_TIME,
         cached_weight: 0,
     });
-    let mut n = 0;
 
     while match best_state.as_ref() {
         None => false,
         Some(state) => state.remaining_time > 0,
     } {
-        n += 1;
         let mut current_state = best_state.unwrap();
 
         current_state.released_pressure += current_state.pressure_per_second;
@@ -155,7 +125,7 @@ pub(crate) fn part1(buffer: &[u8]) -> String {
                 Action::OpenValve => true,
                 Action::Move(last_pos) => current_valve.neighbors[i] != last_pos,
             } {
-                let mut new_state = current_state.clone();
+                let mut new_state = current_state;
                 new_state.position = current_valve.neighbors[i];
                 new_state.last_action = Some(Action::Move(current_state.position));
                 new_state.cached_weight = new_state.weight();
@@ -13,74 +13,76 @@ mod day12;
 mod day13;
 mod day14;
 mod day15;
+mod day16;
 
-use crate::day::{Day, Solutions};
+use aoc_core::Solutions;
 
-pub(crate) fn get_day<'a>(day: usize) -> Day<'a> {
-    Day {
-        day,
-        solutions: match day {
-            1 => Some(Solutions {
-                part1: vec![&day01::part1],
-                part2: vec![&day01::part2],
-            }),
-            2 => Some(Solutions {
-                part1: vec![&day02::part1],
-                part2: vec![&day02::part2],
-            }),
-            3 => Some(Solutions {
-                part1: vec![&day03::part1],
-                part2: vec![&day03::part2],
-            }),
-            4 => Some(Solutions {
-                part1: vec![&day04::part1],
-                part2: vec![&day04::part2],
-            }),
-            5 => Some(Solutions {
-                part1: vec![&day05::part1_stack, &day05::part1_memory],
-                part2: vec![&day05::part2_stack, &day05::part2_memory],
-            }),
-            6 => Some(Solutions {
-                part1: vec![&day06::part1],
-                part2: vec![&day06::part2],
-            }),
-            7 => Some(Solutions {
-                part1: vec![&day07::part1],
-                part2: vec![&day07::part2],
-            }),
-            8 => Some(Solutions {
-                part1: vec![&day08::part1, &day08::part1_unfinished_stack_forest_surveyor, &day08::part1_unfinished_mem_forest_surveyor],
-                part2: vec![&day08::part2, &day08::part2_surveyor],
-            }),
-            9 => Some(Solutions {
-                part1: vec![&day09::part1],
-                part2: vec![&day09::part2],
-            }),
-            10 => Some(Solutions {
-                part1: vec![&day10::part1],
-                part2: vec![&day10::part2],
-            }),
-            11 => Some(Solutions {
-                part1: vec![&day11::part1, &day11::part1_old],
-                part2: vec![&day11::part2, &day11::part2_old, &day11::part2_oldest],
-            }),
-            12 => Some(Solutions {
-                part1: vec![&day12::part1, &day12::part1_old],
-                part2: vec![&day12::part2, &day12::part2_old],
-            }),
-            13 => Some(Solutions {
-                part1: vec![&day13::part1],
-                part2: vec![&day13::part2, &day13::part2_old],
-            }),
-            14 => Some(Solutions {
-                part1: vec![&day14::part1, &day14::part1_simple, &day14::part1_complex_hashset],
-                part2: vec![&day14::part2, &day14::part2_simple, &day14::part2_complex_hashset],
-            }),
-            15 => Some(Solutions {
-                part1: vec![&day15::part1],
-                part2: vec![&day15::part2],
-            }),
-            _ => None
-        }
+pub(crate) fn solutions<'a>(day: usize) -> Option<Solutions<'a>> {
+    match day {
+        1 => Some(Solutions {
+            part1: vec![("main", &day01::part1)],
+            part2: vec![("main", &day01::part2)],
+        }),
+        2 => Some(Solutions {
+            part1: vec![("main", &day02::part1)],
+            part2: vec![("main", &day02::part2)],
+        }),
+        3 => Some(Solutions {
+            part1: vec![("main", &day03::part1)],
+            part2: vec![("main", &day03::part2)],
+        }),
+        4 => Some(Solutions {
+            part1: vec![("main", &day04::part1)],
+            part2: vec![("main", &day04::part2)],
+        }),
+        5 => Some(Solutions {
+            part1: vec![("main", &day05::part1_stack), ("memory", &day05::part1_memory)],
+            part2: vec![("main", &day05::part2_stack), ("memory", &day05::part2_memory)],
+        }),
+        6 => Some(Solutions {
+            part1: vec![("main", &day06::part1)],
+            part2: vec![("main", &day06::part2)],
+        }),
+        7 => Some(Solutions {
+            part1: vec![("main", &day07::part1)],
+            part2: vec![("main", &day07::part2)],
+        }),
+        8 => Some(Solutions {
+            part1: vec![("main", &day08::part1), ("unfinished_stack_forest_surveyor", &day08::part1_unfinished_stack_forest_surveyor), ("unfinished_mem_forest_surveyor", &day08::part1_unfinished_mem_forest_surveyor), ("grid", &day08::part1_grid)],
+            part2: vec![("main", &day08::part2), ("surveyor", &day08::part2_surveyor), ("grid", &day08::part2_grid)],
+        }),
+        9 => Some(Solutions {
+            part1: vec![("main", &day09::part1)],
+            part2: vec![("main", &day09::part2)],
+        }),
+        10 => Some(Solutions {
+            part1: vec![("main", &day10::part1)],
+            part2: vec![("main", &day10::part2)],
+        }),
+        11 => Some(Solutions {
+            part1: vec![("main", &day11::part1), ("old", &day11::part1_old)],
+            part2: vec![("main", &day11::part2), ("old", &day11::part2_old), ("oldest", &day11::part2_oldest)],
+        }),
+        12 => Some(Solutions {
+            part1: vec![("main", &day12::part1), ("old", &day12::part1_old)],
+            part2: vec![("main", &day12::part2), ("old", &day12::part2_old)],
+        }),
+        13 => Some(Solutions {
+            part1: vec![("main", &day13::part1)],
+            part2: vec![("main", &day13::part2), ("old", &day13::part2_old)],
+        }),
+        14 => Some(Solutions {
+            part1: vec![("main", &day14::part1), ("simple", &day14::part1_simple), ("complex_hashset", &day14::part1_complex_hashset)],
+            part2: vec![("main", &day14::part2), ("simple", &day14::part2_simple), ("complex_hashset", &day14::part2_complex_hashset)],
+        }),
+        15 => Some(Solutions {
+            part1: vec![("main", &day15::part1), ("range_set", &day15::part1_range_set)],
+            part2: vec![("main", &day15::part2)],
+        }),
+        16 => Some(Solutions {
+            part1: vec![("main", &day16::part1)],
+            part2: vec![("unfinished", &day16::part2)],
+        }),
+        _ => None
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,6 @@
+use aoc2022::Aoc2022;
+
+#[test]
+fn puzzle_examples() {
+    aoc_core::fixtures::check(&Aoc2022);
+}
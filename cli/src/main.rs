@@ -0,0 +1,300 @@
+use aoc_core::answers::Answers;
+use aoc_core::baseline;
+use aoc_core::day::{Day, RunOptions, Selection, Summary, Verification};
+use aoc_core::input::{InputSource, Normalization};
+use aoc_core::pool::run_ordered;
+use aoc_core::Year;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+use clap::{CommandFactory, Parser};
+use clap::error::ErrorKind;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Year of the puzzles to run
+    #[arg(short, long, default_value_t = 2022, value_parser = parse_year)]
+    year: usize,
+
+    /// Time N samples of each part and report their statistics
+    #[arg(short, long)]
+    benchmark: Option<usize>,
+
+    /// Untimed iterations to run before sampling in benchmark mode
+    #[arg(short, long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Day to run, or a range of days such as `5..=10` or `5..11`
+    #[arg(short, long, value_parser = parse_days)]
+    day: Option<RangeInclusive<usize>>,
+
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Only run the variant registered under this name, see --all-variants for the names
+    #[arg(short = 'V', long, conflicts_with = "all_variants")]
+    variant: Option<String>,
+
+    /// Input file, directory of inputs named after the day, or `-` for the standard input
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Reject inputs with CRLF line endings, a byte order mark or no final line break instead of fixing them
+    #[arg(long)]
+    strict: bool,
+
+    /// Run every registered variant of each part and compare their answers
+    #[arg(short, long)]
+    all_variants: bool,
+
+    /// Check every variant against the known answers instead of printing them
+    #[arg(long, conflicts_with_all = ["benchmark", "all_variants"])]
+    verify: bool,
+
+    /// Run the days and parts on N threads, still printing their output in order
+    #[arg(short, long, default_value_t = 1, conflicts_with = "benchmark")]
+    jobs: usize,
+
+    /// Print the progress and debug output of the solvers to stderr
+    #[arg(short, long)]
+    trace: bool,
+
+    /// Save the benchmark results as the named baseline
+    #[arg(long, requires = "benchmark")]
+    save_baseline: Option<String>,
+
+    /// Compare the benchmark results against the named baseline
+    #[arg(long, requires = "benchmark")]
+    baseline: Option<String>,
+
+    /// Slowdown, in percent of the baseline median, above which a solution counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// A single part of a day, the unit of work handed to the thread pool.
+struct Task {
+    date: usize,
+    part: usize,
+    input: usize,
+    first: bool,
+    last: bool,
+}
+
+enum Outcome {
+    Ran(Summary),
+    Verified(Verification),
+}
+
+const YEARS: [&dyn Year; 3] = [&aoc2016::Aoc2016, &aoc2021::Aoc2021, &aoc2022::Aoc2022];
+
+fn find_year(year: usize) -> Option<&'static dyn Year> {
+    return YEARS.into_iter().find(|candidate| candidate.year() == year);
+}
+
+/// Exits like clap does for an invalid argument, for the checks that need the solutions.
+fn usage_error(message: String) -> ! {
+    Args::command().error(ErrorKind::InvalidValue, message).exit();
+}
+
+fn parse_year(argument: &str) -> Result<usize, String> {
+    let year = argument.parse().map_err(|_| format!("{argument} is not a year"))?;
+    return match find_year(year) {
+        Some(_) => Ok(year),
+        None => {
+            let years: Vec<String> = YEARS.iter().map(|year| year.year().to_string()).collect();
+            Err(format!("{year} has no solutions, available: {}", years.join(", ")))
+        }
+    };
+}
+
+fn parse_days(argument: &str) -> Result<RangeInclusive<usize>, String> {
+    let parse = |day: &str| day.trim().parse::<usize>().map_err(|_| format!("{day} is not a day"));
+
+    let days = if let Some((start, end)) = argument.split_once("..=") {
+        parse(start)?..=parse(end)?
+    } else if let Some((start, end)) = argument.split_once("..") {
+        // The end of an exclusive range is one past the last day, such as 26 in `5..26`
+        match parse(end)?.checked_sub(1) {
+            Some(last) => parse(start)?..=last,
+            None => return Err(format!("{argument} does not contain any day")),
+        }
+    } else {
+        let day = parse(argument)?;
+        day..=day
+    };
+
+    if days.is_empty() {
+        return Err(format!("{argument} does not contain any day"));
+    }
+    for day in [days.start(), days.end()] {
+        if !(1..=25).contains(day) {
+            return Err(format!("{day} is not a day between 1 and 25"));
+        }
+    }
+    return Ok(days);
+}
+
+fn main() {
+    let args = Args::parse();
+    let year = find_year(args.year).unwrap();
+    if args.trace {
+        aoc_core::trace::enable();
+    }
+
+    let days = args.day.clone().unwrap_or(1..=25);
+    let single_day = days.start() == days.end();
+
+    let source = InputSource::from_argument(args.input.as_deref(), year);
+    if source.is_single() && !single_day {
+        panic!("A single input can only be used together with a single --day")
+    }
+
+    let normalization = match args.strict {
+        true => Normalization::Strict,
+        false => Normalization::Lenient,
+    };
+
+    let selection = Selection {
+        part: args.part.map(usize::from),
+        variant: args.variant.clone(),
+    };
+
+    let options = RunOptions {
+        benchmark: args.benchmark,
+        warmup: args.warmup,
+        all_variants: args.all_variants,
+    };
+
+    let answers = match args.verify {
+        true => Some(Answers::load(&year.answers_path()).unwrap_or_else(|e| panic!("{e}"))),
+        false => None,
+    };
+
+    let mut inputs = Vec::new();
+    let mut tasks = Vec::new();
+    let mut available: Vec<String> = Vec::new();
+    for date in days.clone() {
+        let day = Day::new(year, date);
+        if day.solutions.is_none() {
+            // The days are solved in order, a range stops at the first unsolved one
+            if date == *days.start() {
+                usage_error(format!("day {date} of {} is not solved yet", year.year()));
+            }
+            break;
+        }
+
+        // The days without the requested variant are skipped
+        let parts = day.parts(&selection);
+        if parts.is_empty() {
+            for name in day.variant_names() {
+                if !available.iter().any(|known| known == name) {
+                    available.push(name.to_string());
+                }
+            }
+            continue;
+        }
+        for (index, part) in parts.iter().enumerate() {
+            tasks.push(Task {
+                date,
+                part: *part,
+                input: inputs.len(),
+                first: index == 0,
+                last: index == parts.len() - 1,
+            });
+        }
+        inputs.push(source.read(year, date, normalization).unwrap_or_else(|e| panic!("{e}")));
+    }
+
+    if let (true, Some(variant)) = (tasks.is_empty(), &selection.variant) {
+        usage_error(format!("no selected day has a variant named {variant}, available: {}", available.join(", ")));
+    }
+
+    let mut total_time = Duration::ZERO;
+    let mut disagreements = 0;
+    let mut records = Vec::new();
+    let mut verification = Verification::default();
+
+    let work = |index: usize| {
+        let task: &Task = &tasks[index];
+        let day = Day::new(year, task.date);
+        let selection = Selection { part: Some(task.part), variant: selection.variant.clone() };
+        let mut output = String::new();
+        let outcome = match &answers {
+            Some(answers) => Outcome::Verified(day.verify(&inputs[task.input], &selection, answers, &mut output)),
+            None => Outcome::Ran(day.run(&inputs[task.input], &selection, &options, &mut output)),
+        };
+        return (output, outcome);
+    };
+
+    run_ordered(tasks.len(), args.jobs, work, |index, (output, outcome)| {
+        let task = &tasks[index];
+        if task.first {
+            println!();
+            if !single_day {
+                let width = 20;
+                println!("{}Day {:02}{}", " ".repeat(width), task.date, " ".repeat(width));
+                println!("{}", "═".repeat(width * 2 + 6));
+            }
+        }
+        print!("{output}");
+        if task.last {
+            println!();
+        }
+
+        match outcome {
+            Outcome::Verified(part_verification) => {
+                verification.passed += part_verification.passed;
+                verification.failed += part_verification.failed;
+                verification.missing += part_verification.missing;
+                verification.unfinished += part_verification.unfinished;
+            }
+            Outcome::Ran(summary) => {
+                total_time += summary.duration;
+                disagreements += summary.disagreements;
+                records.extend(summary.records);
+            }
+        }
+    });
+
+    if args.benchmark.is_some() {
+        println!("The total median time was {:.3} ms", total_time.as_secs_f64() * 1000.0);
+        println!()
+    }
+
+    let mut failed = false;
+
+    if args.verify {
+        println!(
+            "{} passed, {} failed, {} missing, {} unfinished",
+            verification.passed, verification.failed, verification.missing, verification.unfinished,
+        );
+        failed |= verification.failed > 0;
+    }
+
+    if let Some(name) = &args.baseline {
+        let baseline = baseline::load(&year.baseline_directory(), name).unwrap_or_else(|e| panic!("{e}"));
+        let regressions = baseline::compare(&baseline, &records, args.threshold);
+        println!();
+        if regressions > 0 {
+            println!("\x1b[1m\x1b[91m{regressions} solution(s) regressed by more than {}% against {name}\x1b[0m", args.threshold);
+            failed = true;
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let path = baseline::save(&year.baseline_directory(), name, &records).unwrap_or_else(|e| panic!("{e}"));
+        println!("Saved baseline to {}", path.display());
+    }
+
+    if disagreements > 0 {
+        println!("\x1b[1m\x1b[91m{disagreements} variant(s) disagree with the main solution\x1b[0m");
+        failed = true;
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
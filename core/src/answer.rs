@@ -0,0 +1,70 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    /// Anything that is not a plain number, such as letters rendered on a screen.
+    Text(String),
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        return match answer.parse::<i128>() {
+            Ok(integer) if integer.to_string() == answer => Answer::Integer(integer),
+            _ => Answer::Text(answer),
+        };
+    }
+}
+
+macro_rules! integer_answer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(answer: $integer) -> Self {
+                    return Answer::Integer(answer as i128);
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        return Answer::from(answer.to_string());
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(answer) => write!(f, "{answer}"),
+            Answer::Text(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The day is not between 1 and 25, or has not been solved yet.
+    UnknownDay(usize),
+    UnknownPart(usize),
+    UnknownVariant { day: usize, part: usize, variant: String },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownDay(day) => write!(f, "Day {day} has no solution"),
+            SolveError::UnknownPart(part) => write!(f, "Invalid part {part}, expected 1 or 2"),
+            SolveError::UnknownVariant { day, part, variant } => {
+                write!(f, "Day {day:02} part {part} has no variant named {variant}")
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
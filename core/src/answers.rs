@@ -0,0 +1,86 @@
+use std::fs::read_to_string;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Known-correct answers, keyed by zero-padded day then by part:
+///
+/// ```toml
+/// [05]
+/// part1 = "CMZ"
+/// part2 = 12
+/// ```
+///
+/// Variants still being written are listed under `unfinished`, for the verification to skip
+/// them: `unfinished = ["memory"]`.
+pub struct Answers {
+    table: Table,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        let table = contents.parse::<Table>().map_err(|e| format!("Could not parse {}: {e}", path.display()))?;
+        return Ok(Answers { table });
+    }
+
+    pub fn expected(&self, day: usize, part: usize) -> Option<String> {
+        return self.get(&format!("{day:02}"), part);
+    }
+
+    /// The names of the variants of a day that are not expected to find its answers yet.
+    pub fn unfinished(&self, day: usize) -> Vec<String> {
+        let Some(Value::Array(names)) = self.table.get(&format!("{day:02}")).and_then(|table| table.get("unfinished")) else {
+            return Vec::new();
+        };
+        return names.iter().filter_map(|name| name.as_str().map(String::from)).collect();
+    }
+
+    /// The expected answer of a part in the table called `name`.
+    pub fn get(&self, name: &str, part: usize) -> Option<String> {
+        let value = self.table.get(name)?.get(format!("part{part}"))?;
+        return match value {
+            Value::String(answer) => Some(answer.clone()),
+            Value::Integer(answer) => Some(answer.to_string()),
+            _ => None,
+        };
+    }
+
+    /// The names of every table, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        return self.table.keys();
+    }
+}
+
+/// Leading and trailing line breaks are not significant, multi-line answers
+/// such as rendered letters are easier to write in TOML without them.
+pub fn same_answer(expected: &str, actual: &str) -> bool {
+    expected.trim_matches('\n') == actual.trim_matches('\n')
+}
+
+/// Renders the expected and actual answers, line by line for multi-line answers.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.trim_matches('\n');
+    let actual = actual.trim_matches('\n');
+
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return format!("    \x1b[91m- expected: {expected}\x1b[0m\n    \x1b[92m+ actual:   {actual}\x1b[0m\n");
+    }
+
+    let mut diff = String::new();
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    for line in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(line), actual_lines.get(line)) {
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!("      {e}\n")),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push_str(&format!("    \x1b[91m- {e}\x1b[0m\n"));
+                }
+                if let Some(a) = a {
+                    diff.push_str(&format!("    \x1b[92m+ {a}\x1b[0m\n"));
+                }
+            }
+        }
+    }
+    return diff;
+}
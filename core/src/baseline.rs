@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::benchmark::{micros, Statistics};
+
+const HEADER: &str = "# day\tpart\tvariant\tmin_ns\tmedian_ns\tmean_ns\tp95_ns\tstddev_ns";
+
+/// Benchmark result of a single variant, as saved in a baseline file.
+pub struct Record {
+    pub day: usize,
+    pub part: usize,
+    pub variant: String,
+    pub statistics: Statistics,
+}
+
+type Key = (usize, usize, String);
+
+fn baseline_path(directory: &Path, name: &str) -> PathBuf {
+    return directory.join(format!("{name}.tsv"));
+}
+
+pub fn save(directory: &Path, name: &str, records: &[Record]) -> Result<PathBuf, String> {
+    let mut contents = String::from(HEADER);
+    contents.push('\n');
+    for record in records {
+        let statistics = &record.statistics;
+        contents.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            record.day,
+            record.part,
+            record.variant,
+            statistics.min.as_nanos(),
+            statistics.median.as_nanos(),
+            statistics.mean.as_nanos(),
+            statistics.p95.as_nanos(),
+            statistics.stddev.as_nanos(),
+        ));
+    }
+
+    let path = baseline_path(directory, name);
+    create_dir_all(directory).map_err(|e| format!("Could not create {}: {e}", directory.display()))?;
+    write(&path, contents).map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+    return Ok(path);
+}
+
+/// Loads the median time of every variant saved in the baseline.
+pub fn load(directory: &Path, name: &str) -> Result<HashMap<Key, Duration>, String> {
+    let path = baseline_path(directory, name);
+    let contents = read_to_string(&path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+
+    let mut medians = HashMap::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        let malformed = || format!("{}:{}: malformed baseline entry", path.display(), line_number + 1);
+        if fields.len() < 5 {
+            return Err(malformed());
+        }
+
+        let day = fields[0].parse().map_err(|_| malformed())?;
+        let part = fields[1].parse().map_err(|_| malformed())?;
+        let median = fields[4].parse().map_err(|_| malformed())?;
+        medians.insert((day, part, fields[2].to_string()), Duration::from_nanos(median));
+    }
+    return Ok(medians);
+}
+
+/// Prints the median time delta of every record against the baseline and returns
+/// how many of them got slower by more than `threshold` percent.
+pub fn compare(baseline: &HashMap<Key, Duration>, records: &[Record], threshold: f64) -> usize {
+    let width = records.iter().map(|record| record.variant.len()).max().unwrap_or(0);
+    let mut regressions = 0;
+
+    for record in records {
+        let current = record.statistics.median;
+        let key = (record.day, record.part, record.variant.clone());
+        let label = format!("Day {:02} part {} {:<width$}", record.day, record.part, record.variant);
+
+        let previous = match baseline.get(&key) {
+            None => {
+                println!("{label}  {:>12.1} μs   (not in baseline)", micros(current));
+                continue;
+            }
+            Some(previous) => *previous,
+        };
+
+        let delta = match previous.is_zero() {
+            true => 0.0,
+            false => (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0,
+        };
+        let color = if delta > threshold {
+            regressions += 1;
+            91
+        } else if delta < -threshold {
+            92
+        } else {
+            0
+        };
+        println!(
+            "{label}  {:>12.1} μs -> {:>12.1} μs   \x1b[1m\x1b[{color}m{delta:>+7.1}%\x1b[0m",
+            micros(previous),
+            micros(current),
+        );
+    }
+
+    return regressions;
+}
@@ -0,0 +1,120 @@
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Tukey fence multiplier, samples further than this many inter-quartile ranges
+/// away from the first or third quartile are rejected as outliers.
+const OUTLIER_FENCE: f64 = 1.5;
+
+#[derive(Copy, Clone)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    pub samples: usize,
+    pub outliers: usize,
+}
+
+pub struct Measurement {
+    pub answer: String,
+    pub statistics: Statistics,
+}
+
+/// Sorted slice lookup using the nearest-rank method.
+fn percentile(sorted: &[u128], percent: usize) -> u128 {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    return sorted[rank - 1];
+}
+
+fn median(sorted: &[u128]) -> u128 {
+    let middle = sorted.len() / 2;
+    return match sorted.len() % 2 {
+        0 => (sorted[middle - 1] + sorted[middle]) / 2,
+        _ => sorted[middle],
+    };
+}
+
+/// Drops the samples outside of the Tukey fences. Expects a sorted slice.
+fn reject_outliers(sorted: &[u128]) -> &[u128] {
+    if sorted.len() < 4 {
+        return sorted;
+    }
+
+    let first_quartile = percentile(sorted, 25) as f64;
+    let third_quartile = percentile(sorted, 75) as f64;
+    let spread = (third_quartile - first_quartile) * OUTLIER_FENCE;
+    let (low, high) = (first_quartile - spread, third_quartile + spread);
+
+    let start = sorted.partition_point(|&sample| (sample as f64) < low);
+    let end = sorted.partition_point(|&sample| (sample as f64) <= high);
+    return &sorted[start..end];
+}
+
+impl Statistics {
+    fn from_samples(mut samples: Vec<u128>) -> Self {
+        samples.sort_unstable();
+        let kept = reject_outliers(&samples);
+
+        let mean = kept.iter().sum::<u128>() as f64 / kept.len() as f64;
+        let variance = match kept.len() {
+            1 => 0.0,
+            amount => kept.iter()
+                .map(|&sample| (sample as f64 - mean).powi(2))
+                .sum::<f64>() / (amount - 1) as f64,
+        };
+
+        return Statistics {
+            min: Duration::from_nanos(kept[0] as u64),
+            median: Duration::from_nanos(median(kept) as u64),
+            mean: Duration::from_nanos(mean as u64),
+            p95: Duration::from_nanos(percentile(kept, 95) as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            samples: kept.len(),
+            outliers: samples.len() - kept.len(),
+        };
+    }
+}
+
+pub fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "min {:>12.1} μs   median {:>12.1} μs   mean {:>12.1} μs   p95 {:>12.1} μs   σ {:>10.1} μs   ({} samples, {} outliers)",
+            micros(self.min),
+            micros(self.median),
+            micros(self.mean),
+            micros(self.p95),
+            micros(self.stddev),
+            self.samples,
+            self.outliers,
+        ))
+    }
+}
+
+/// Runs `warmup` untimed iterations of the solution, then times `samples` iterations of it.
+/// Both the input and the answer go through `black_box` so the work cannot be optimized away.
+pub fn measure(solution: &dyn Fn(&[u8]) -> String, buffer: &[u8], warmup: usize, samples: usize) -> Measurement {
+    let samples = samples.max(1);
+
+    for _ in 0..warmup {
+        black_box(solution(black_box(buffer)));
+    }
+
+    let mut answer = String::new();
+    let mut timings = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = Instant::now();
+        answer = black_box(solution(black_box(buffer)));
+        timings.push(start.elapsed().as_nanos());
+    }
+
+    return Measurement {
+        answer,
+        statistics: Statistics::from_samples(timings),
+    };
+}
@@ -0,0 +1,233 @@
+use std::time::Duration;
+use crate::answers::{diff, same_answer, Answers};
+use crate::baseline::Record;
+use crate::benchmark::{measure, Measurement};
+use crate::year::{Solution, Solutions, Year};
+
+pub struct Day<'a> {
+    pub day: usize,
+    pub solutions: Option<Solutions<'a>>,
+}
+
+impl Day<'static> {
+    pub fn new(year: &dyn Year, day: usize) -> Self {
+        return Day { day, solutions: year.solutions(day) };
+    }
+}
+
+pub struct RunOptions {
+    pub benchmark: Option<usize>,
+    pub warmup: usize,
+    pub all_variants: bool,
+}
+
+impl RunOptions {
+    fn measure(&self, solution: &dyn Fn(&[u8]) -> String, buffer: &[u8]) -> Measurement {
+        return match self.benchmark {
+            None => measure(solution, buffer, 0, 1),
+            Some(samples) => measure(solution, buffer, self.warmup, samples),
+        };
+    }
+}
+
+/// Which parts and variants of a day to run, everything when unset.
+pub struct Selection {
+    pub part: Option<usize>,
+    pub variant: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Verification {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+    /// Variants marked as unfinished in the answers, which are not run.
+    pub unfinished: usize,
+}
+
+pub struct Summary {
+    pub duration: Duration,
+    pub disagreements: usize,
+    pub records: Vec<Record>,
+}
+
+impl Day<'_> {
+    /// # Panics
+    ///
+    /// Panics when the day has not been solved, or the part is neither 1 nor 2.
+    fn variants(&self, part: usize) -> &[Solution<'_>] {
+        let solutions = self.solutions.as_ref().unwrap_or_else(|| panic!("Day {:02} has no solutions", self.day));
+        return match part {
+            1 => &solutions.part1,
+            2 => &solutions.part2,
+            _ => panic!("Day {:02} has no part {part}, only 1 and 2", self.day),
+        };
+    }
+
+    /// Lists the selected parts along with their selected variants, skipping the parts
+    /// that do not register the requested variant, so all of them when the day does not.
+    fn select(&self, selection: &Selection) -> Vec<(usize, Vec<Solution<'_>>)> {
+        let parts = match selection.part {
+            None => vec![1, 2],
+            Some(part) => vec![part],
+        };
+
+        let mut selected = Vec::new();
+        for part in parts {
+            let variants: Vec<Solution<'_>> = self.variants(part).iter()
+                .filter(|(name, _)| selection.variant.as_deref().is_none_or(|variant| *name == variant))
+                .copied()
+                .collect();
+            if !variants.is_empty() {
+                selected.push((part, variants));
+            }
+        }
+        return selected;
+    }
+
+    /// The names of the variants registered for either part, the main one first.
+    pub fn variant_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        for (name, _) in self.variants(1).iter().chain(self.variants(2)) {
+            if !names.contains(name) {
+                names.push(*name);
+            }
+        }
+        return names;
+    }
+
+    /// The parts that have at least one selected variant, none when the day does not register
+    /// the requested one.
+    pub fn parts(&self, selection: &Selection) -> Vec<usize> {
+        return self.select(selection).into_iter().map(|(part, _)| part).collect();
+    }
+
+    fn record(&self, part: usize, variant: &str, measurement: &Measurement) -> Record {
+        return Record {
+            day: self.day,
+            part,
+            variant: variant.to_string(),
+            statistics: measurement.statistics,
+        };
+    }
+
+    fn run_part(&self, part: usize, variants: &[Solution<'_>], buffer: &[u8], options: &RunOptions, out: &mut String) -> Summary {
+        let (name, solution) = variants[0];
+        let measurement = options.measure(solution, buffer);
+        out.push_str(&format!("Part {part}: \x1b[1m\x1b[92m{}\x1b[0m\n", measurement.answer));
+        if options.benchmark.is_some() {
+            out.push_str(&format!("{}\n", measurement.statistics));
+        }
+        return Summary {
+            duration: measurement.statistics.median,
+            disagreements: 0,
+            records: vec![self.record(part, name, &measurement)],
+        };
+    }
+
+    /// Runs every registered variant of a part and compares their answers against the first one.
+    fn cross_check_part(&self, part: usize, variants: &[Solution<'_>], buffer: &[u8], options: &RunOptions, out: &mut String) -> Summary {
+        let width = variants.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+        let mut summary = Summary { duration: Duration::ZERO, disagreements: 0, records: Vec::new() };
+        let mut reference: Option<String> = None;
+
+        out.push_str(&format!("Part {part}:\n"));
+        for (name, solution) in variants {
+            let measurement = options.measure(*solution, buffer);
+            summary.records.push(self.record(part, name, &measurement));
+            let answer = measurement.answer;
+            let agrees = match &reference {
+                None => true,
+                Some(expected) => *expected == answer,
+            };
+            let color = match agrees {
+                true => 92,
+                false => 91,
+            };
+            out.push_str(&match options.benchmark {
+                None => format!(
+                    "  {name:<width$} {:>10} μs  \x1b[1m\x1b[{color}m{answer}\x1b[0m\n",
+                    measurement.statistics.median.as_micros(),
+                ),
+                Some(_) => format!(
+                    "  {name:<width$}  {}  \x1b[1m\x1b[{color}m{answer}\x1b[0m\n",
+                    measurement.statistics,
+                ),
+            });
+
+            match reference {
+                None => {
+                    summary.duration = measurement.statistics.median;
+                    reference = Some(answer);
+                }
+                Some(_) if !agrees => summary.disagreements += 1,
+                Some(_) => {}
+            }
+        }
+
+        if summary.disagreements > 0 {
+            out.push_str(&format!(
+                "\x1b[1m\x1b[91m{} variant(s) of part {part} disagree with {}\x1b[0m\n",
+                summary.disagreements, variants[0].0,
+            ));
+        }
+        return summary;
+    }
+
+    /// Runs every registered variant once and checks its answer against the expected one, except
+    /// the unfinished ones.
+    pub fn verify(&self, input: &str, selection: &Selection, answers: &Answers, out: &mut String) -> Verification {
+        let mut verification = Verification::default();
+
+        for (part, variants) in self.select(selection) {
+            let width = variants.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+            let expected = answers.expected(self.day, part);
+            let unfinished = answers.unfinished(self.day);
+
+            for (name, solution) in variants {
+                let label = format!("Day {:02} part {part} {name:<width$}", self.day);
+                if unfinished.iter().any(|unfinished| unfinished == name) {
+                    verification.unfinished += 1;
+                    out.push_str(&format!("{label}  \x1b[1m\x1b[90mUNFINISHED\x1b[0m\n"));
+                    continue;
+                }
+
+                let actual = solution(input.as_bytes());
+                match &expected {
+                    None => {
+                        verification.missing += 1;
+                        out.push_str(&format!("{label}  \x1b[1m\x1b[93mMISSING\x1b[0m\n"));
+                    }
+                    Some(expected) if same_answer(expected, &actual) => {
+                        verification.passed += 1;
+                        out.push_str(&format!("{label}  \x1b[1m\x1b[92mPASS\x1b[0m\n"));
+                    }
+                    Some(expected) => {
+                        verification.failed += 1;
+                        out.push_str(&format!("{label}  \x1b[1m\x1b[91mFAIL\x1b[0m\n"));
+                        out.push_str(&diff(expected, &actual));
+                    }
+                }
+            }
+        }
+
+        return verification;
+    }
+
+    pub fn run(&self, input: &str, selection: &Selection, options: &RunOptions, out: &mut String) -> Summary {
+        let mut summary = Summary { duration: Duration::ZERO, disagreements: 0, records: Vec::new() };
+
+        for (part, variants) in self.select(selection) {
+            let part_summary = match options.all_variants {
+                true => self.cross_check_part(part, &variants, input.as_bytes(), options, out),
+                false => self.run_part(part, &variants, input.as_bytes(), options, out),
+            };
+            summary.duration += part_summary.duration;
+            summary.disagreements += part_summary.disagreements;
+            summary.records.extend(part_summary.records);
+        }
+
+        return summary;
+    }
+}
@@ -0,0 +1,135 @@
+//! The directions of the walks and scans over maps, on the screen: up is towards the first line
+//! of the input, so a step [`Direction::Up`] decreases `y`, like in a [`Grid`](crate::grid::Grid).
+
+use crate::parser::{Cursor, Expected, ParseError};
+use crate::pattern::Field;
+use crate::vector::Vector2D;
+
+/// One of the eight directions, clockwise from [`Direction::Up`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The directions along the axes, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// All the directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Reads the letters `U`, `D`, `L` and `R`, the cardinal points `N`, `E`, `S` and `W` with
+    /// north being up, and the arrows `^`, `v`, `<` and `>`.
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        return match byte {
+            b'U' | b'N' | b'^' => Some(Direction::Up),
+            b'R' | b'E' | b'>' => Some(Direction::Right),
+            b'D' | b'S' | b'v' => Some(Direction::Down),
+            b'L' | b'W' | b'<' => Some(Direction::Left),
+            _ => None,
+        };
+    }
+
+    /// The direction after turning by eighths of a full turn, clockwise.
+    #[inline]
+    fn rotate(self, eighths: usize) -> Self {
+        return Direction::ALL[(self as usize + eighths) % 8];
+    }
+
+    /// A quarter turn counterclockwise.
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        return self.rotate(6);
+    }
+
+    /// A quarter turn clockwise.
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        return self.rotate(2);
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Self {
+        return self.rotate(4);
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        return self as usize % 2 == 1;
+    }
+
+    /// A step of one square in this direction, diagonals moving along both axes.
+    #[inline]
+    pub fn step<T: From<i8>>(self) -> Vector2D<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        };
+        return Vector2D::new(T::from(x), T::from(y));
+    }
+}
+
+/// One byte in any of the encodings of [`Direction::from_byte`], for `{Direction}` fields.
+impl Field<'_> for Direction {
+    #[inline]
+    fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        return match cursor.peek().and_then(Direction::from_byte) {
+            Some(direction) => {
+                cursor.skip(1);
+                Ok(direction)
+            }
+            None => Err(cursor.error(Expected::OneOf(b"UDLRNESW^v<>"))),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_around() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpRight.reverse(), Direction::DownLeft);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().step::<i32>(), -direction.step::<i32>());
+            assert_eq!(direction.is_diagonal(), direction.step::<i32>().manhattan_length() == 2);
+        }
+    }
+
+    #[test]
+    fn reads_the_encodings() {
+        for (encodings, direction) in [(b"UN^", Direction::Up), (b"RE>", Direction::Right), (b"DSv", Direction::Down), (b"LW<", Direction::Left)] {
+            for byte in encodings {
+                assert_eq!(Direction::from_byte(*byte), Some(direction));
+            }
+        }
+
+        let mut cursor = Cursor::new(b"R 4\nX 2\n");
+        assert_eq!(crate::parse!(&mut cursor, {Direction} " " {u8} "\n"), Ok((Direction::Right, 4)));
+        assert_eq!(crate::parse!(&mut cursor, {Direction} " " {u8} "\n").unwrap_err().column(), 1);
+    }
+}
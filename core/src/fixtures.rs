@@ -0,0 +1,89 @@
+//! The worked examples of the puzzle statements, checked by the tests of every year crate.
+//!
+//! Each example is a file in the fixture directory of its year, named after its day with an
+//! optional suffix when a day has several examples, such as `09` and `09-larger`. The
+//! `answers.toml` next to them holds the expected answers keyed by file name, with only the
+//! parts the example covers:
+//!
+//! ```toml
+//! [09]
+//! part1 = 13
+//! part2 = 1
+//!
+//! [09-larger]
+//! part2 = 36
+//! ```
+//!
+//! Every variant of a part must find the answer, except the ones the `answers.toml` of the
+//! year's inputs lists as unfinished.
+
+use std::fs::read_to_string;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use crate::answers::{diff, same_answer, Answers};
+use crate::input::{normalize, Normalization};
+use crate::year::Year;
+
+/// The day of a fixture, from the digits its name starts with.
+fn day_of(name: &str) -> Option<usize> {
+    return name.split('-').next()?.parse::<usize>().ok();
+}
+
+/// Runs every finished variant of the parts covered by the fixtures of a year.
+///
+/// # Panics
+///
+/// Panics once every example has run, listing the ones that failed or panicked.
+pub fn check(year: &dyn Year) {
+    let directory = year.fixture_directory();
+    let answers = Answers::load(&directory.join("answers.toml")).unwrap_or_else(|e| panic!("{e}"));
+    let inputs = match year.answers_path().exists() {
+        true => Some(Answers::load(&year.answers_path()).unwrap_or_else(|e| panic!("{e}"))),
+        false => None,
+    };
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for name in answers.names() {
+        let Some(day) = day_of(name) else {
+            failures.push(format!("{name}: the name does not start with a day\n"));
+            continue;
+        };
+        // Leniently, as a checkout on Windows may have turned the line endings into CRLF
+        let input = match read_to_string(directory.join(name)) {
+            Ok(input) => normalize(input, Normalization::Lenient).unwrap(),
+            Err(e) => {
+                failures.push(format!("{name}: could not read the example: {e}\n"));
+                continue;
+            }
+        };
+
+        let unfinished = inputs.as_ref().map(|inputs| inputs.unfinished(day)).unwrap_or_default();
+        for part in 1..=2 {
+            let Some(expected) = answers.get(name, part) else {
+                continue;
+            };
+            let variants = match year.variants(day, part) {
+                Ok(variants) => variants,
+                Err(e) => {
+                    failures.push(format!("{name} part {part}: {e}\n"));
+                    continue;
+                }
+            };
+            for (variant, solution) in variants {
+                if unfinished.iter().any(|unfinished| unfinished == variant) {
+                    continue;
+                }
+                checked += 1;
+                match catch_unwind(AssertUnwindSafe(|| solution(input.as_bytes()))) {
+                    Ok(actual) if same_answer(&expected, &actual) => {}
+                    Ok(actual) => failures.push(format!("{name} part {part} {variant}:\n{}", diff(&expected, &actual))),
+                    Err(_) => failures.push(format!("{name} part {part} {variant}: the solver panicked\n")),
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("{} of {checked} examples of {} failed:\n{}", failures.len(), year.year(), failures.concat());
+    }
+}
@@ -0,0 +1,241 @@
+//! Rectangular maps of cells, such as the forests and height maps drawn in the inputs, indexed by
+//! [`Vector2D`] with `x` growing to the right and `y` growing downwards.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::direction::Direction;
+use crate::parser::{scan, Cursor, Expected, ParseError};
+use crate::vector::Vector2D;
+
+/// The cells of a map, row after row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    size: Vector2D<usize>,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A map with every cell set to `cell`.
+    pub fn new(size: Vector2D<usize>, cell: T) -> Self {
+        return Grid { size, cells: vec![cell; size.x * size.y] };
+    }
+
+    /// The map mirrored along its diagonal, its columns becoming rows.
+    pub fn transpose(&self) -> Self {
+        return Grid::from_fn(Vector2D::new(self.size.y, self.size.x), |position| {
+            self[Vector2D::new(position.y, position.x)].clone()
+        });
+    }
+}
+
+impl<T> Grid<T> {
+    /// A map with each cell computed from its position, row after row.
+    pub fn from_fn(size: Vector2D<usize>, mut cell: impl FnMut(Vector2D<usize>) -> T) -> Self {
+        let cells = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| Vector2D::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        return Grid { size, cells };
+    }
+
+    /// Reads a map drawn with one byte per cell, all its lines as long as the first one. The last
+    /// line break is optional.
+    pub fn parse(buffer: &[u8], mut cell: impl FnMut(u8) -> T) -> Result<Self, ParseError> {
+        let width = scan::find_byte(buffer, b'\n').unwrap_or(buffer.len());
+        let mut cells = Vec::with_capacity(buffer.len());
+        let mut height = 0;
+        let mut start = 0;
+        while start < buffer.len() {
+            let line = &buffer[start..];
+            let length = scan::find_byte(line, b'\n').unwrap_or(line.len());
+            if length != width {
+                let mut cursor = Cursor::new(buffer);
+                cursor.skip(start + length.min(width));
+                return Err(cursor.error(Expected::RowOfWidth(width)));
+            }
+            cells.extend(line[..width].iter().map(|byte| cell(*byte)));
+            height += 1;
+            start += width + 1;
+        }
+        return Ok(Grid { size: Vector2D::new(width, height), cells });
+    }
+
+    pub fn size(&self) -> Vector2D<usize> {
+        return self.size;
+    }
+
+    pub fn width(&self) -> usize {
+        return self.size.x;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.size.y;
+    }
+
+    #[inline]
+    pub fn contains(&self, position: Vector2D<usize>) -> bool {
+        return position.x < self.size.x && position.y < self.size.y;
+    }
+
+    #[inline]
+    pub fn get(&self, position: Vector2D<usize>) -> Option<&T> {
+        return match self.contains(position) {
+            true => Some(&self.cells[position.y * self.size.x + position.x]),
+            false => None,
+        };
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, position: Vector2D<usize>) -> Option<&mut T> {
+        return match self.contains(position) {
+            true => Some(&mut self.cells[position.y * self.size.x + position.x]),
+            false => None,
+        };
+    }
+
+    /// The cells, row after row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        return self.cells.iter();
+    }
+
+    /// The positions of the cells, in the same order as [`Grid::iter`].
+    pub fn positions(&self) -> impl Iterator<Item = Vector2D<usize>> {
+        let width = self.size.x;
+        return (0..self.size.y).flat_map(move |y| (0..width).map(move |x| Vector2D::new(x, y)));
+    }
+
+    /// The position of the first cell matching `predicate`, row after row.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Vector2D<usize>> {
+        let offset = self.cells.iter().position(predicate)?;
+        return Some(Vector2D::new(offset % self.size.x, offset / self.size.x));
+    }
+
+    /// The position one step away in `direction`, unless it is outside of the map.
+    #[inline]
+    pub fn neighbour(&self, position: Vector2D<usize>, direction: Direction) -> Option<Vector2D<usize>> {
+        let step = direction.step::<isize>();
+        let neighbour = Vector2D::new(position.x.checked_add_signed(step.x)?, position.y.checked_add_signed(step.y)?);
+        return self.contains(neighbour).then_some(neighbour);
+    }
+
+    /// The positions sharing a side with `position` inside the map, clockwise from the one above.
+    #[inline]
+    pub fn neighbours4(&self, position: Vector2D<usize>) -> impl Iterator<Item = Vector2D<usize>> + '_ {
+        return Direction::ORTHOGONAL.into_iter().filter_map(move |direction| self.neighbour(position, direction));
+    }
+
+    /// The positions sharing a side or a corner with `position` inside the map, clockwise from
+    /// the one above.
+    #[inline]
+    pub fn neighbours8(&self, position: Vector2D<usize>) -> impl Iterator<Item = Vector2D<usize>> + '_ {
+        return Direction::ALL.into_iter().filter_map(move |direction| self.neighbour(position, direction));
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.size.x..(y + 1) * self.size.x];
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        return &mut self.cells[y * self.size.x..(y + 1) * self.size.x];
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        return (0..self.size.y).map(|y| self.row(y));
+    }
+
+    /// The cells of a column from top to bottom. Scanning many columns is faster on the
+    /// [transposed](Grid::transpose) map, where they are rows.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.size.x, "Column {x} is outside of a map {} wide", self.size.x);
+        return self.cells[x..].iter().step_by(self.size.x);
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + ExactSizeIterator {
+        return (0..self.size.x).map(|x| self.column(x));
+    }
+
+    /// The same map with each cell converted, such as heights parsed from digits.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { size: self.size, cells: self.cells.iter().map(cell).collect() };
+    }
+}
+
+impl<T> Index<Vector2D<usize>> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, position: Vector2D<usize>) -> &Self::Output {
+        return self.get(position).unwrap_or_else(|| panic!("{position} is outside of a map of size {}", self.size));
+    }
+}
+
+impl<T> IndexMut<Vector2D<usize>> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, position: Vector2D<usize>) -> &mut Self::Output {
+        let size = self.size;
+        return self.get_mut(position).unwrap_or_else(|| panic!("{position} is outside of a map of size {size}"));
+    }
+}
+
+/// Draws the cells row after row, without a line break after the last one.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u8> {
+        return Grid::parse(b"123\n456\n", |digit| digit - b'0').unwrap();
+    }
+
+    #[test]
+    fn parses_and_draws() {
+        let grid = digits();
+        assert_eq!(grid.size(), Vector2D::new(3, 2));
+        assert_eq!(grid[Vector2D::new(2, 1)], 6);
+        assert_eq!(grid.get(Vector2D::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(Grid::parse(b"123\n456", |digit| digit - b'0').unwrap(), grid);
+        assert_eq!(grid.find(|digit| *digit == 5), Some(Vector2D::new(1, 1)));
+
+        let error = Grid::parse(b"123\n45\n", |digit| digit).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.expected()), (2, 3, Expected::RowOfWidth(3)));
+        let error = Grid::parse(b"123\n4567\n", |digit| digit).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.found()), (2, 4, Some(b'7')));
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let grid = digits();
+        let neighbours: Vec<_> = grid.neighbours4(Vector2D::new(0, 0)).collect();
+        assert_eq!(neighbours, [Vector2D::new(1, 0), Vector2D::new(0, 1)]);
+        let neighbours: Vec<u8> = grid.neighbours8(Vector2D::new(1, 1)).map(|position| grid[position]).collect();
+        assert_eq!(neighbours, [2, 3, 6, 4, 1]);
+        assert_eq!(grid.neighbour(Vector2D::new(2, 0), Direction::DownLeft), Some(Vector2D::new(1, 1)));
+        assert_eq!(grid.neighbour(Vector2D::new(2, 0), Direction::Right), None);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = digits();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(1).rev().copied().collect::<Vec<_>>(), [5, 2]);
+        assert_eq!(grid.columns().len(), 3);
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "14\n25\n36");
+        assert_eq!(transposed.transpose(), grid);
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs::read_to_string;
+use std::io::{read_to_string as read_all, stdin};
+use std::path::PathBuf;
+use crate::year::Year;
+
+/// Where the puzzle inputs are read from.
+pub enum InputSource {
+    /// A directory holding one input per day, named like the checked-in inputs of the year.
+    Directory(PathBuf),
+    /// A single input file, only usable when running one day.
+    File(PathBuf),
+    /// The standard input, only usable when running one day.
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets the `--input` argument: `-` is the standard input, a directory holds
+    /// an input per day and anything else is a file. Defaults to the checked-in inputs.
+    pub fn from_argument(argument: Option<&str>, year: &dyn Year) -> Self {
+        return match argument {
+            None => InputSource::Directory(year.input_directory()),
+            Some("-") => InputSource::Stdin,
+            Some(path) if PathBuf::from(path).is_dir() => InputSource::Directory(PathBuf::from(path)),
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        };
+    }
+
+    /// Whether the source only holds the input of a single day.
+    pub fn is_single(&self) -> bool {
+        return !matches!(self, InputSource::Directory(_));
+    }
+
+    pub fn read(&self, year: &dyn Year, day: usize, normalization: Normalization) -> Result<String, String> {
+        let (name, input) = match self {
+            InputSource::Directory(directory) => {
+                let path = directory.join(year.input_name(day));
+                (path.display().to_string(), read_to_string(&path))
+            }
+            InputSource::File(path) => (path.display().to_string(), read_to_string(path)),
+            InputSource::Stdin => (String::from("the standard input"), read_all(stdin())),
+        };
+        let input = input.map_err(|e| format!("Could not read {name}: {e}"))?;
+        return normalize(input, normalization).map_err(|e| format!("Malformed input in {name}: {e}"));
+    }
+}
+
+/// How [`normalize`] treats the inputs saved by other editors and platforms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Removes a byte order mark, turns CRLF line endings into LF and adds a missing final
+    /// line break.
+    Lenient,
+    /// Rejects the inputs that would need any of these fixes.
+    Strict,
+}
+
+/// Brings an input to the shape the solvers expect: LF line endings, a line break after the
+/// last line and no byte order mark.
+pub fn normalize(mut input: String, normalization: Normalization) -> Result<String, String> {
+    if normalization == Normalization::Strict {
+        if input.starts_with('\u{feff}') {
+            return Err(String::from("it starts with a byte order mark"));
+        }
+        if let Some(offset) = input.find('\r') {
+            let line = input[..offset].matches('\n').count() + 1;
+            return Err(format!("line {line} ends with a carriage return, only LF line endings are accepted"));
+        }
+        if !input.is_empty() && !input.ends_with('\n') {
+            return Err(String::from("the last line does not end with a line break"));
+        }
+        return Ok(input);
+    }
+
+    if let Some(stripped) = input.strip_prefix('\u{feff}') {
+        input = stripped.to_string();
+    }
+    if input.contains('\r') {
+        input = input.replace("\r\n", "\n");
+    }
+    if !input.is_empty() && !input.ends_with('\n') {
+        input.push('\n');
+    }
+    return Ok(input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixes_inputs_saved_on_windows() {
+        let input = String::from("\u{feff}1,2\r\n3,4");
+        assert_eq!(normalize(input, Normalization::Lenient), Ok(String::from("1,2\n3,4\n")));
+    }
+
+    #[test]
+    fn rejects_them_in_strict_mode() {
+        let strict = |input: &str| normalize(input.to_string(), Normalization::Strict);
+        assert_eq!(strict("1,2\n3,4\n"), Ok(String::from("1,2\n3,4\n")));
+        assert_eq!(strict("1,2\n3,4\r\n"), Err(String::from("line 2 ends with a carriage return, only LF line endings are accepted")));
+        assert_eq!(strict("\u{feff}1,2\n"), Err(String::from("it starts with a byte order mark")));
+        assert_eq!(strict("1,2"), Err(String::from("the last line does not end with a line break")));
+    }
+}
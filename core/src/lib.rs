@@ -0,0 +1,25 @@
+//! Shared building blocks of the Advent of Code solutions: the parsers and geometry used by
+//! the solvers, the [`Year`] trait every year crate implements, and the runner behind the
+//! `aoc` binary.
+
+mod answer;
+pub mod answers;
+pub mod array_utils;
+pub mod baseline;
+pub mod benchmark;
+pub mod day;
+pub mod direction;
+pub mod fixtures;
+pub mod grid;
+pub mod input;
+pub mod parser;
+pub mod pattern;
+pub mod pool;
+pub mod range_set;
+pub mod search;
+pub mod trace;
+pub mod vector;
+mod year;
+
+pub use crate::answer::{Answer, SolveError};
+pub use crate::year::{Solution, Solutions, Year};
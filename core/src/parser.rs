@@ -0,0 +1,353 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use num_traits::{NumCast, PrimInt, WrappingAdd, WrappingMul, WrappingSub};
+
+pub mod scan;
+
+/// What a parser was looking for when it failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    UnsignedInt,
+    SignedInt,
+    /// An integer small enough for the named type, such as `u8`.
+    IntFitting(&'static str),
+    Word,
+    /// Letters and digits, such as the names of valves.
+    Identifier,
+    Byte(u8),
+    Literal(&'static [u8]),
+    /// Any of the given bytes, such as the first letters of the known instructions.
+    OneOf(&'static [u8]),
+    /// A line of a map as long as the first one.
+    RowOfWidth(usize),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::UnsignedInt => write!(f, "an unsigned integer"),
+            Expected::SignedInt => write!(f, "a signed integer"),
+            Expected::IntFitting(name) => write!(f, "an integer fitting in {name}"),
+            Expected::Word => write!(f, "a word"),
+            Expected::Identifier => write!(f, "an identifier"),
+            Expected::Byte(byte) => write!(f, "{:?}", *byte as char),
+            Expected::Literal(literal) => write!(f, "{:?}", String::from_utf8_lossy(literal)),
+            Expected::OneOf(bytes) => {
+                let bytes: Vec<String> = bytes.iter().map(|byte| format!("{:?}", *byte as char)).collect();
+                write!(f, "one of {}", bytes.join(", "))
+            }
+            Expected::RowOfWidth(width) => write!(f, "a row of {width} cells"),
+        }
+    }
+}
+
+/// A parser failure, located in the input by byte offset and by 1-based line and column.
+///
+/// The details are boxed, so that the results of the parsers stay small on their fast path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(Box<Failure>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Failure {
+    offset: usize,
+    line: usize,
+    column: usize,
+    expected: Expected,
+    found: Option<u8>,
+}
+
+impl ParseError {
+    pub fn offset(&self) -> usize {
+        return self.0.offset;
+    }
+
+    pub fn line(&self) -> usize {
+        return self.0.line;
+    }
+
+    pub fn column(&self) -> usize {
+        return self.0.column;
+    }
+
+    pub fn expected(&self) -> Expected {
+        return self.0.expected;
+    }
+
+    /// The byte found instead, `None` at the end of the input.
+    pub fn found(&self) -> Option<u8> {
+        return self.0.found;
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected {} at line {}, column {}, ", self.0.expected, self.0.line, self.0.column)?;
+        match self.0.found {
+            Some(byte) => write!(f, "found {:?}", byte as char),
+            None => write!(f, "found the end of the input"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The integer types [`Cursor::read_int`] reads, every primitive one.
+pub trait Int: PrimInt + WrappingAdd + WrappingSub + WrappingMul {}
+
+impl<T: PrimInt + WrappingAdd + WrappingSub + WrappingMul> Int for T {}
+
+/// The value of a long number, `None` when it does not fit in `T`.
+#[cold]
+#[inline(never)]
+fn checked_int<T: Int>(digits: &[u8], negative: bool) -> Option<T> {
+    let ten: T = NumCast::from(10).unwrap();
+    let mut value = T::zero();
+    for digit in digits {
+        let digit: T = NumCast::from(digit - b'0').unwrap();
+        value = match negative {
+            true => value.checked_mul(&ten)?.checked_sub(&digit)?,
+            false => value.checked_mul(&ten)?.checked_add(&digit)?,
+        };
+    }
+    return Some(value);
+}
+
+/// A position in a puzzle input, moving forward as it is parsed. Reading at or past the end of
+/// the input returns a [`ParseError`] instead of panicking.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        return Cursor { buffer, offset: 0 };
+    }
+
+    pub fn buffer(&self) -> &'a [u8] {
+        return self.buffer;
+    }
+
+    pub fn offset(&self) -> usize {
+        return self.offset;
+    }
+
+    #[inline]
+    pub fn is_at_end(&self) -> bool {
+        return self.offset >= self.buffer.len();
+    }
+
+    #[inline]
+    pub fn peek(&self) -> Option<u8> {
+        return self.buffer.get(self.offset).copied();
+    }
+
+    /// The byte `distance` bytes ahead of the cursor.
+    #[inline]
+    pub fn peek_at(&self, distance: usize) -> Option<u8> {
+        return self.buffer.get(self.offset.saturating_add(distance)).copied();
+    }
+
+    /// Moves forward without looking at the bytes, possibly past the end of the input.
+    #[inline]
+    pub fn skip(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+
+    /// Moves past the given byte, failing without moving when the cursor is not on it.
+    #[inline]
+    pub fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.peek() != Some(byte) {
+            return Err(self.error(Expected::Byte(byte)));
+        }
+        self.offset += 1;
+        return Ok(());
+    }
+
+    /// Moves past the given bytes, failing without moving when the input differs.
+    #[inline]
+    pub fn expect_literal(&mut self, literal: &'static [u8]) -> Result<(), ParseError> {
+        let end = self.offset.checked_add(literal.len());
+        if end.and_then(|end| self.buffer.get(self.offset..end)) != Some(literal) {
+            return Err(self.error(Expected::Literal(literal)));
+        }
+        self.offset += literal.len();
+        return Ok(());
+    }
+
+    /// Reads an integer of any width, preceded by a `-` when `T` is signed. Fails without moving,
+    /// at the start of the number, when it does not fit in `T` instead of wrapping around.
+    #[inline]
+    pub fn read_int<T: Int>(&mut self) -> Result<T, ParseError> {
+        let start = self.offset;
+        let signed = T::min_value() < T::zero();
+        let negative = signed && self.peek() == Some(b'-');
+        if negative {
+            self.offset += 1;
+        }
+
+        if !matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.offset = start;
+            return Err(self.error(if signed { Expected::SignedInt } else { Expected::UnsignedInt }));
+        }
+
+        // Negative numbers are accumulated below zero, as the minimum of a signed type has no
+        // positive counterpart
+        let ten: T = NumCast::from(10).unwrap();
+        let digits_start = self.offset;
+        let mut value = T::zero();
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            let digit: T = NumCast::from(digit - b'0').unwrap();
+            value = match negative {
+                true => value.wrapping_mul(&ten).wrapping_sub(&digit),
+                false => value.wrapping_mul(&ten).wrapping_add(&digit),
+            };
+            self.offset += 1;
+        }
+
+        // Only numbers longer than the ones any value of `T` can hold, such as 9 digits for 32
+        // bits, can have wrapped around
+        let safe_digits = match size_of::<T>() {
+            1 => 2,
+            2 => 4,
+            4 => 9,
+            8 => 18,
+            _ => 38,
+        };
+        if self.offset - digits_start > safe_digits {
+            return match checked_int(&self.buffer[digits_start..self.offset], negative) {
+                Some(value) => Ok(value),
+                None => {
+                    self.offset = start;
+                    Err(self.error(Expected::IntFitting(type_name::<T>())))
+                }
+            };
+        }
+        return Ok(value);
+    }
+
+    #[inline]
+    pub fn read_unsigned_int(&mut self) -> Result<usize, ParseError> {
+        return self.read_int();
+    }
+
+    #[inline]
+    pub fn read_signed_int(&mut self) -> Result<isize, ParseError> {
+        return self.read_int();
+    }
+
+    #[inline]
+    pub fn skip_word(&mut self) {
+        while let Some(b'a'..=b'z') = self.peek() {
+            self.offset += 1;
+        }
+    }
+
+    #[inline]
+    pub fn read_word(&mut self) -> Result<&'a [u8], ParseError> {
+        let starting_offset = self.offset;
+        self.skip_word();
+
+        if starting_offset == self.offset {
+            return Err(self.error(Expected::Word));
+        }
+
+        return Ok(&self.buffer[starting_offset..self.offset]);
+    }
+
+    #[inline]
+    pub fn read_identifier(&mut self) -> Result<&'a [u8], ParseError> {
+        let starting_offset = self.offset;
+        while let Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9') = self.peek() {
+            self.offset += 1;
+        }
+
+        if starting_offset == self.offset {
+            return Err(self.error(Expected::Identifier));
+        }
+
+        return Ok(&self.buffer[starting_offset..self.offset]);
+    }
+
+    /// Moves past the next line break, or to the end of the input on the last line.
+    #[inline]
+    pub fn move_to_next_line(&mut self) {
+        self.offset = match scan::find_byte(&self.buffer[self.offset.min(self.buffer.len())..], b'\n') {
+            Some(line_break) => self.offset + line_break + 1,
+            None => self.buffer.len(),
+        };
+    }
+
+    /// An error at the cursor, for the checks the days make themselves.
+    #[cold]
+    pub fn error(&self, expected: Expected) -> ParseError {
+        let offset = self.offset.min(self.buffer.len());
+        let before = &self.buffer[..offset];
+        let line_start = before.iter().rposition(|byte| *byte == b'\n').map_or(0, |line_break| line_break + 1);
+        return ParseError(Box::new(Failure {
+            offset,
+            line: before.iter().filter(|byte| **byte == b'\n').count() + 1,
+            column: offset - line_start + 1,
+            expected,
+            found: self.peek(),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_last_number_without_a_line_break() {
+        let mut cursor = Cursor::new(b"12\n345");
+        assert_eq!(cursor.read_unsigned_int(), Ok(12));
+        cursor.expect(b'\n').unwrap();
+        assert_eq!(cursor.read_unsigned_int(), Ok(345));
+        assert!(cursor.is_at_end());
+    }
+
+    #[test]
+    fn locates_errors() {
+        let mut cursor = Cursor::new(b"move 1\nmove x");
+        cursor.move_to_next_line();
+        cursor.skip(5);
+        let error = cursor.read_unsigned_int().unwrap_err();
+        assert_eq!((error.offset(), error.line(), error.column()), (12, 2, 6));
+        assert_eq!(error.found(), Some(b'x'));
+        assert_eq!(error.to_string(), "Expected an unsigned integer at line 2, column 6, found 'x'");
+    }
+
+    #[test]
+    fn detects_overflows() {
+        assert_eq!(Cursor::new(b"255,").read_int::<u8>(), Ok(255));
+        assert_eq!(Cursor::new(b"-128,").read_int::<i8>(), Ok(-128));
+        assert_eq!(Cursor::new(b"340282366920938463463374607431768211455").read_int::<u128>(), Ok(u128::MAX));
+
+        let mut cursor = Cursor::new(b"x=-129");
+        cursor.skip(2);
+        let error = cursor.read_int::<i8>().unwrap_err();
+        assert_eq!((error.column(), error.expected()), (3, Expected::IntFitting("i8")));
+        assert_eq!(cursor.offset(), 2);
+        assert_eq!(error.to_string(), "Expected an integer fitting in i8 at line 1, column 3, found '-'");
+        assert!(Cursor::new(b"18446744073709551616").read_unsigned_int().is_err());
+    }
+
+    #[test]
+    fn stops_at_the_end() {
+        let mut cursor = Cursor::new(b"-");
+        assert_eq!(cursor.read_signed_int().unwrap_err().expected(), Expected::SignedInt);
+        cursor.skip(10);
+        cursor.move_to_next_line();
+        assert_eq!(cursor.peek(), None);
+        let error = cursor.read_word().unwrap_err();
+        assert_eq!((error.offset(), error.found()), (1, None));
+
+        cursor.skip(usize::MAX);
+        assert_eq!(cursor.peek_at(1), None);
+        let error = cursor.expect_literal(b"x").unwrap_err();
+        assert_eq!((error.offset(), error.expected()), (1, Expected::Literal(b"x")));
+    }
+}
@@ -0,0 +1,109 @@
+//! Vectorized scans of the input, picking once the widest instructions the processor supports.
+//! Every scan has a scalar version, used on other processors and near the end of the input,
+//! that the vectorized ones must agree with.
+//!
+//! The numbers of the inputs are too short for vectors to pay off: reading them 16 digits at a
+//! time was slower than [`Cursor::read_int`](super::Cursor::read_int) digit by digit.
+
+use std::sync::OnceLock;
+
+type FindByte = fn(&[u8], u8) -> Option<usize>;
+
+/// The offset of the first `needle` in `haystack`.
+#[inline]
+pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    static FIND_BYTE: OnceLock<FindByte> = OnceLock::new();
+    let find_byte = FIND_BYTE.get_or_init(|| {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return x86::find_byte_avx2_detected;
+            }
+            return x86::find_byte_sse2;
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        return scalar::find_byte;
+    });
+    return find_byte(haystack, needle);
+}
+
+pub(crate) mod scalar {
+    pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+        return haystack.iter().position(|byte| *byte == needle);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod x86 {
+    use std::arch::x86_64::*;
+    use super::scalar;
+
+    /// Only picked once AVX2 is detected.
+    pub fn find_byte_avx2_detected(haystack: &[u8], needle: u8) -> Option<usize> {
+        // SAFETY: the processor supports AVX2
+        return unsafe { find_byte_avx2(haystack, needle) };
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn find_byte_avx2(haystack: &[u8], needle: u8) -> Option<usize> {
+        let needles = _mm256_set1_epi8(needle as i8);
+        let mut offset = 0;
+        while offset + 32 <= haystack.len() {
+            let chunk = _mm256_loadu_si256(haystack.as_ptr().add(offset) as *const __m256i);
+            let matches = _mm256_movemask_epi8(_mm256_cmpeq_epi8(chunk, needles)) as u32;
+            if matches != 0 {
+                return Some(offset + matches.trailing_zeros() as usize);
+            }
+            offset += 32;
+        }
+        return find_byte_sse2(&haystack[offset..], needle).map(|found| offset + found);
+    }
+
+    pub fn find_byte_sse2(haystack: &[u8], needle: u8) -> Option<usize> {
+        let mut offset = 0;
+        while offset + 16 <= haystack.len() {
+            // SAFETY: SSE2 is part of x86_64, and the 16 bytes are in the haystack
+            let matches = unsafe {
+                let chunk = _mm_loadu_si128(haystack.as_ptr().add(offset) as *const __m128i);
+                _mm_movemask_epi8(_mm_cmpeq_epi8(chunk, _mm_set1_epi8(needle as i8))) as u32
+            };
+            if matches != 0 {
+                return Some(offset + matches.trailing_zeros() as usize);
+            }
+            offset += 16;
+        }
+        return scalar::find_byte(&haystack[offset..], needle).map(|found| offset + found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Lines of a few bytes to a few vectors long.
+    fn bytes() -> impl Strategy<Value = Vec<u8>> {
+        let byte = prop_oneof![
+            20 => any::<u8>(),
+            1 => Just(b'\n'),
+        ];
+        return prop::collection::vec(byte, 0..200);
+    }
+
+    proptest! {
+        #[test]
+        fn find_byte_agrees(bytes in bytes(), needle in prop_oneof![Just(b'\n'), any::<u8>()]) {
+            let expected = scalar::find_byte(&bytes, needle);
+            prop_assert_eq!(find_byte(&bytes, needle), expected);
+
+            #[cfg(target_arch = "x86_64")]
+            {
+                prop_assert_eq!(x86::find_byte_sse2(&bytes, needle), expected);
+                if is_x86_feature_detected!("avx2") {
+                    prop_assert_eq!(unsafe { x86::find_byte_avx2(&bytes, needle) }, expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Declarative parsing of input lines, with the fixed parts of a line written out and the values
+//! between braces:
+//!
+//! ```
+//! use aoc_core::parse;
+//! use aoc_core::parser::Cursor;
+//!
+//! let mut cursor = Cursor::new(b"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB\n");
+//! let (name, flow, tunnels) =
+//!     parse!(&mut cursor, "Valve " {id} " has flow rate=" {u32} "; tunnel" {s?} " lead" {s?} " to valve" {s?} " " {list:id} "\n").unwrap();
+//! assert_eq!((name, flow), (&b"AA"[..], 0));
+//! assert_eq!(tunnels.iter().collect::<Vec<_>>(), [b"DD", b"II", b"BB"]);
+//! ```
+//!
+//! The values are any [`Field`], such as `{u32}` or `{i64}`, `{word}` for lowercase letters,
+//! `{id}` for letters and digits, and `{list:value}` for values separated by `", "`. `{c?}`
+//! skips an optional letter `c`. The macro expands to the calls a day would make on the
+//! [`Cursor`] itself, so it costs nothing over hand-written skips, and allocates nothing.
+
+use crate::parser::{Cursor, ParseError};
+
+/// The values a [`parse!`](crate::parse) pattern reads by their type.
+pub trait Field<'a>: Sized {
+    fn read(cursor: &mut Cursor<'a>) -> Result<Self, ParseError>;
+}
+
+macro_rules! integer_field {
+    ($($integer:ty),*) => {
+        $(
+            impl Field<'_> for $integer {
+                #[inline]
+                fn read(cursor: &mut Cursor) -> Result<Self, ParseError> {
+                    return cursor.read_int();
+                }
+            }
+        )*
+    };
+}
+
+integer_field!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The values of a `{list:value}` field, read again when iterated.
+#[derive(Debug, Clone, Copy)]
+pub struct List<'a, T> {
+    elements: &'a [u8],
+    read: fn(&mut Cursor<'a>) -> Result<T, ParseError>,
+}
+
+impl<'a, T: 'a> List<'a, T> {
+    /// The elements must have been read successfully with `read`, separated by `", "`.
+    pub fn new(elements: &'a [u8], read: fn(&mut Cursor<'a>) -> Result<T, ParseError>) -> Self {
+        return List { elements, read };
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        let read = self.read;
+        let mut cursor = Cursor::new(self.elements);
+        return std::iter::from_fn(move || {
+            if cursor.is_at_end() {
+                return None;
+            }
+            let element = read(&mut cursor).unwrap();
+            cursor.skip(2);
+            return Some(element);
+        });
+    }
+}
+
+/// Reads a line matching the pattern into a tuple of its values, failing at the first
+/// difference. See the [module](crate::pattern) documentation.
+#[macro_export]
+macro_rules! parse {
+    ($cursor:expr, $($segments:tt)+) => {
+        'parse: {
+            let cursor: &mut $crate::parser::Cursor = $cursor;
+            $crate::parse!(@segments 'parse cursor [] [] $($segments)+)
+        }
+    };
+
+    // The literals and optional letters are checked before the next value is read
+    (@segments $label:lifetime $cursor:ident [$($fields:tt)*] [$($pending:tt)*] $literal:literal $($rest:tt)*) => {
+        $crate::parse!(@segments $label $cursor [$($fields)*] [
+            $($pending)*
+            $crate::parse!(@try $label $cursor.expect_literal($literal.as_bytes()));
+        ] $($rest)*)
+    };
+    (@segments $label:lifetime $cursor:ident [$($fields:tt)*] [$($pending:tt)*] {$letter:ident ?} $($rest:tt)*) => {
+        $crate::parse!(@segments $label $cursor [$($fields)*] [
+            $($pending)*
+            if $cursor.peek() == Some(stringify!($letter).as_bytes()[0]) {
+                $cursor.skip(1);
+            }
+        ] $($rest)*)
+    };
+    (@segments $label:lifetime $cursor:ident [$($fields:tt)*] [$($pending:tt)*] {list: $kind:tt} $($rest:tt)*) => {
+        $crate::parse!(@segments $label $cursor [$($fields)* {
+            $($pending)*
+            let start = $cursor.offset();
+            $crate::parse!(@try $label $crate::parse!(@read $cursor $kind));
+            while $cursor.peek() == Some(b',') {
+                $crate::parse!(@try $label $cursor.expect_literal(b", "));
+                $crate::parse!(@try $label $crate::parse!(@read $cursor $kind));
+            }
+            $crate::pattern::List::new(&$cursor.buffer()[start..$cursor.offset()], |cursor| $crate::parse!(@read cursor $kind))
+        },] [] $($rest)*)
+    };
+    (@segments $label:lifetime $cursor:ident [$($fields:tt)*] [$($pending:tt)*] {$kind:tt} $($rest:tt)*) => {
+        $crate::parse!(@segments $label $cursor [$($fields)* {
+            $($pending)*
+            $crate::parse!(@try $label $crate::parse!(@read $cursor $kind))
+        },] [] $($rest)*)
+    };
+    (@segments $label:lifetime $cursor:ident [$($fields:tt)*] [$($pending:tt)*]) => {{
+        let fields = ($($fields)*);
+        $($pending)*
+        Ok(fields)
+    }};
+
+    (@read $cursor:ident word) => { $cursor.read_word() };
+    (@read $cursor:ident id) => { $cursor.read_identifier() };
+    (@read $cursor:ident $kind:ty) => { <$kind as $crate::pattern::Field>::read($cursor) };
+
+    (@try $label:lifetime $result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(error) => break $label Err(error),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Expected;
+
+    fn parse_move(cursor: &mut Cursor) -> Result<(usize, usize, usize), ParseError> {
+        return parse!(cursor, "move " {usize} " from " {usize} " to " {usize} "\n");
+    }
+
+    #[test]
+    fn reads_the_fields_in_order() {
+        let mut cursor = Cursor::new(b"move 3 from 1 to 2\nmove 1 from 2 to 1\n");
+        assert_eq!(parse_move(&mut cursor), Ok((3, 1, 2)));
+        assert_eq!(cursor.offset(), 19);
+    }
+
+    #[test]
+    fn locates_differences() {
+        let mut cursor = Cursor::new(b"move 3 from 1 to 2\nmove 1 form 2 to 1\n");
+        parse_move(&mut cursor).unwrap();
+        let error = parse_move(&mut cursor).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 7));
+        assert_eq!(error.expected(), Expected::Literal(b" from "));
+    }
+
+    #[test]
+    fn checks_the_range_of_integers() {
+        let (offset,): (i8,) = parse!(&mut Cursor::new(b"x=-128"), "x=" {i8}).unwrap();
+        assert_eq!(offset, -128);
+        let error = parse!(&mut Cursor::new(b"x=128"), "x=" {i8}).unwrap_err();
+        assert_eq!((error.column(), error.expected()), (3, Expected::IntFitting("i8")));
+    }
+
+    #[test]
+    fn reads_lists_and_optional_letters() {
+        let mut cursor = Cursor::new(b"Valve HH has flow rate=22; tunnel leads to valve GG\nValve AA has flow rate=0; tunnels lead to valves DD, II, BB\n");
+        let mut parse_valve = || parse!(&mut cursor, "Valve " {id} " has flow rate=" {u32} "; tunnel" {s?} " lead" {s?} " to valve" {s?} " " {list:id} "\n").unwrap();
+
+        let (name, flow, tunnels) = parse_valve();
+        assert_eq!((name, flow, tunnels.iter().collect::<Vec<_>>()), (&b"HH"[..], 22, vec![&b"GG"[..]]));
+
+        let (name, flow, tunnels) = parse_valve();
+        assert_eq!((name, flow, tunnels.iter().collect::<Vec<_>>()), (&b"AA"[..], 0, vec![&b"DD"[..], b"II", b"BB"]));
+        assert!(cursor.is_at_end());
+    }
+}
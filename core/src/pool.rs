@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::thread::scope;
+
+/// Runs `work` for every task index on `jobs` threads and hands the results to `emit`
+/// in task order, as soon as all the tasks before them are done.
+pub fn run_ordered<T: Send>(
+    tasks: usize,
+    jobs: usize,
+    work: impl Fn(usize) -> T + Sync,
+    mut emit: impl FnMut(usize, T),
+) {
+    if jobs <= 1 {
+        for task in 0..tasks {
+            emit(task, work(task));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = channel();
+
+    scope(|scope| {
+        for _ in 0..jobs.min(tasks) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let task = next.fetch_add(1, Ordering::Relaxed);
+                if task >= tasks || sender.send((task, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending: Vec<Option<T>> = (0..tasks).map(|_| None).collect();
+        let mut emitted = 0;
+        for (task, result) in receiver {
+            pending[task] = Some(result);
+            while emitted < tasks {
+                match pending[emitted].take() {
+                    None => break,
+                    Some(result) => emit(emitted, result),
+                }
+                emitted += 1;
+            }
+        }
+    });
+}
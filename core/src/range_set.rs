@@ -0,0 +1,304 @@
+//! Sets of integers stored as the ranges they cover, such as the positions a sensor sees on a row
+//! or the addresses left out of a blacklist.
+
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+use num_traits::PrimInt;
+
+/// The first and last values within `range`, unbounded ends reaching the limits of `T`, or
+/// `None` when it is empty.
+fn inclusive<T: PrimInt>(range: impl RangeBounds<T>) -> Option<(T, T)> {
+    let first = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.checked_add(&T::one())?,
+        Bound::Unbounded => T::min_value(),
+    };
+    let last = match range.end_bound() {
+        Bound::Included(end) => *end,
+        Bound::Excluded(end) => end.checked_sub(&T::one())?,
+        Bound::Unbounded => T::max_value(),
+    };
+    return (first <= last).then_some((first, last));
+}
+
+/// The amount of values from `first` to `last`, which may not fit in `T` when it is signed.
+fn distance<T: PrimInt>(first: T, last: T) -> u128 {
+    return match (first.to_i128(), last.to_i128()) {
+        (Some(first), Some(last)) => last.abs_diff(first),
+        // Only the unsigned values above the largest `i128` do not fit in it
+        _ => last.to_u128().unwrap() - first.to_u128().unwrap(),
+    };
+}
+
+/// Integers kept as sorted, disjoint ranges, which merge as soon as they overlap or touch.
+///
+/// Every operation takes any kind of range, `3..=5` and `3..6` being the same values. The ranges
+/// are stored by their first and last values, so that a set can hold the largest value of `T`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: PrimInt> RangeSet<T> {
+    pub fn new() -> Self {
+        return RangeSet { ranges: Vec::new() };
+    }
+
+    /// The set of ranges sorted by their first value, merging those that overlap or touch.
+    fn from_sorted(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut merged: Vec<(T, T)> = Vec::new();
+        for (first, last) in ranges {
+            match merged.last_mut() {
+                Some(previous) if first <= previous.1.saturating_add(T::one()) => previous.1 = previous.1.max(last),
+                _ => merged.push((first, last)),
+            }
+        }
+        return RangeSet { ranges: merged };
+    }
+
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let Some((mut first, mut last)) = inclusive(range) else {
+            return;
+        };
+
+        // The ranges overlapping or touching the new one are merged into it
+        let start = self.ranges.partition_point(|existing| existing.1.saturating_add(T::one()) < first);
+        let end = self.ranges.partition_point(|existing| existing.0 <= last.saturating_add(T::one()));
+        if start < end {
+            first = first.min(self.ranges[start].0);
+            last = last.max(self.ranges[end - 1].1);
+        }
+        self.ranges.splice(start..end, [(first, last)]);
+    }
+
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let Some((first, last)) = inclusive(range) else {
+            return;
+        };
+
+        let start = self.ranges.partition_point(|existing| existing.1 < first);
+        let end = self.ranges.partition_point(|existing| existing.0 <= last);
+        if start < end {
+            // Only the first and last overlapping ranges can stick out of the removed one, which
+            // keeps the values around it away from the limits of `T`
+            let (before, after) = (self.ranges[start].0, self.ranges[end - 1].1);
+            let mut kept = Vec::with_capacity(2);
+            if before < first {
+                kept.push((before, first - T::one()));
+            }
+            if last < after {
+                kept.push((last + T::one(), after));
+            }
+            self.ranges.splice(start..end, kept);
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.1 < value);
+        return self.ranges.get(index).is_some_and(|range| range.0 <= value);
+    }
+
+    /// The amount of values in the set, wider than `T` as a set may hold all of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the set holds every value of a 128-bit type, one more than a `u128` can count.
+    pub fn len(&self) -> u128 {
+        return self.ranges.iter().fold(0, |length, (first, last)| {
+            length.checked_add(distance(*first, *last) + 1).expect("Every 128-bit value is in the set, too many to count")
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+
+    /// The ranges of the set in increasing order, neither overlapping nor touching.
+    pub fn ranges(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + ExactSizeIterator + '_ {
+        return self.ranges.iter().map(|(first, last)| *first..=*last);
+    }
+
+    /// The values missing between the first and last ranges of the set, as ranges in increasing
+    /// order.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        // The ranges do not touch, so there is at least one value between two of them
+        return self.ranges.windows(2).map(|pair| pair[0].1 + T::one()..=pair[1].0 - T::one());
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges: Vec<(T, T)> = self.ranges.iter().chain(&other.ranges).copied().collect();
+        ranges.sort_unstable_by_key(|range| range.0);
+        return RangeSet::from_sorted(ranges);
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (0, 0);
+        while left < self.ranges.len() && right < other.ranges.len() {
+            let (a, b) = (self.ranges[left], other.ranges[right]);
+            let overlap = (a.0.max(b.0), a.1.min(b.1));
+            if overlap.0 <= overlap.1 {
+                ranges.push(overlap);
+            }
+
+            // The range ending first cannot overlap anything further in the other set
+            match a.1 < b.1 {
+                true => left += 1,
+                false => right += 1,
+            }
+        }
+        return RangeSet { ranges };
+    }
+
+    /// The values of this set missing from `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        return self.intersection(&other.complement(..));
+    }
+
+    /// The values within `bounds` missing from the set.
+    pub fn complement(&self, bounds: impl RangeBounds<T>) -> Self {
+        let Some((first, last)) = inclusive(bounds) else {
+            return RangeSet::new();
+        };
+
+        // The next value that may be missing, none once a range reaches the largest value of `T`
+        let mut ranges = Vec::new();
+        let mut next = Some(first);
+        for range in &self.ranges {
+            let Some(missing) = next.filter(|missing| *missing <= last) else {
+                break;
+            };
+            if range.0 > missing {
+                ranges.push((missing, (range.0 - T::one()).min(last)));
+            }
+            if range.1 >= missing {
+                next = range.1.checked_add(&T::one());
+            }
+        }
+        if let Some(missing) = next.filter(|missing| *missing <= last) {
+            ranges.push((missing, last));
+        }
+        return RangeSet { ranges };
+    }
+}
+
+impl<T: PrimInt, R: RangeBounds<T>> FromIterator<R> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut ranges: Vec<(T, T)> = iter.into_iter().filter_map(inclusive).collect();
+        ranges.sort_unstable_by_key(|range| range.0);
+        return RangeSet::from_sorted(ranges);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const VALUES: u8 = 64;
+
+    /// Ranges of all kinds over a few values at either limit of `u8`, so that they often overlap
+    /// and touch.
+    fn ranges() -> impl Strategy<Value = Vec<(u8, u8, bool)>> {
+        let bound = || prop_oneof![0..VALUES, u8::MAX - VALUES..=u8::MAX];
+        return prop::collection::vec((bound(), bound(), any::<bool>()), 0..8);
+    }
+
+    fn range_set(ranges: &[(u8, u8, bool)]) -> RangeSet<u8> {
+        let mut set = RangeSet::new();
+        for (start, end, inclusive) in ranges {
+            match inclusive {
+                true => set.insert(*start..=*end),
+                false => set.insert(*start..*end),
+            }
+        }
+        return set;
+    }
+
+    fn values(ranges: &[(u8, u8, bool)]) -> Vec<bool> {
+        return (0..=u8::MAX).map(|value| {
+            ranges.iter().any(|(start, end, inclusive)| match inclusive {
+                true => (*start..=*end).contains(&value),
+                false => (*start..*end).contains(&value),
+            })
+        }).collect();
+    }
+
+    fn assert_valid(set: &RangeSet<u8>) {
+        assert!(set.ranges().all(|range| !range.is_empty()));
+        assert!(set.gaps().all(|gap| !gap.is_empty()), "The ranges overlap or touch: {set:?}");
+    }
+
+    #[test]
+    fn merges_inclusive_and_exclusive_ranges() {
+        let mut set: RangeSet<u32> = [3..=5, 6..=6, 10..=12].into_iter().collect();
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [3..=6, 10..=12]);
+        assert_eq!(set.gaps().next(), Some(7..=9));
+        assert_eq!(set.len(), 7);
+
+        set.remove(4..11);
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [3..=3, 11..=12]);
+        assert_eq!(set.complement(0..20).ranges().collect::<Vec<_>>(), [0..=2, 4..=10, 13..=19]);
+    }
+
+    #[test]
+    fn reaches_the_limits_of_the_type() {
+        let mut set: RangeSet<u8> = [250..=u8::MAX, 0..=0].into_iter().collect();
+        assert!(set.contains(u8::MAX));
+        assert_eq!(set.len(), 7);
+        assert_eq!(set.complement(..).ranges().collect::<Vec<_>>(), [1..=249]);
+        assert!(set.complement(250..).is_empty());
+
+        set.insert(249..);
+        set.remove(u8::MAX..=u8::MAX);
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [0..=0, 249..=254]);
+        assert_eq!(set.complement(..).ranges().collect::<Vec<_>>(), [1..=248, 255..=255]);
+        set.insert(..);
+        assert_eq!((set.ranges().len(), set.len()), (1, 256));
+
+        // The amount of values does not fit in `T`, nor does the distance between the limits of a
+        // signed type
+        let mut set = RangeSet::new();
+        set.insert(..0i64);
+        set.insert(0..);
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [i64::MIN..=i64::MAX]);
+        assert_eq!(set.len(), 1 << 64);
+        let set: RangeSet<u128> = [..=u128::MAX - 1].into_iter().collect();
+        assert_eq!(set.len(), u128::MAX);
+    }
+
+    proptest! {
+        #[test]
+        fn operations_agree_with_the_values(a in ranges(), b in ranges(), removed in ranges()) {
+            let (set_a, set_b) = (range_set(&a), range_set(&b));
+            let (values_a, values_b, values_removed) = (values(&a), values(&b), values(&removed));
+
+            let mut remaining = set_a.clone();
+            for (start, end, inclusive) in &removed {
+                match inclusive {
+                    true => remaining.remove(*start..=*end),
+                    false => remaining.remove(*start..*end),
+                }
+            }
+
+            let union = set_a.union(&set_b);
+            let intersection = set_a.intersection(&set_b);
+            let difference = set_a.difference(&set_b);
+            let complement = set_a.complement(10..50);
+            for set in [&set_a, &remaining, &union, &intersection, &difference, &complement] {
+                assert_valid(set);
+            }
+
+            for value in 0..=u8::MAX {
+                let (in_a, in_b) = (values_a[value as usize], values_b[value as usize]);
+                prop_assert_eq!(set_a.contains(value), in_a);
+                prop_assert_eq!(remaining.contains(value), in_a && !values_removed[value as usize]);
+                prop_assert_eq!(union.contains(value), in_a || in_b);
+                prop_assert_eq!(intersection.contains(value), in_a && in_b);
+                prop_assert_eq!(difference.contains(value), in_a && !in_b);
+                prop_assert_eq!(complement.contains(value), !in_a && (10..50).contains(&value));
+            }
+            prop_assert_eq!(set_a.len() as usize, values_a.iter().filter(|value| **value).count());
+            prop_assert_eq!(set_a.is_empty(), !values_a.contains(&true));
+        }
+    }
+}
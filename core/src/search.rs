@@ -0,0 +1,707 @@
+//! Searches for the cheapest way to a goal through the states of a puzzle. A day only describes
+//! its states with [`SearchState`], then picks a driver: [`bfs`] when every move costs the same,
+//! [`dijkstra`] when they do not, [`a_star`] when the cost left can be estimated, and
+//! [`ida_star`] when there are too many states to remember.
+//!
+//! The drivers are a [`Search`] with a given [`Frontier`] and [`Visited`] set, which can be
+//! swapped for faster ones, such as a [`BucketQueue`] when the costs are small integers or a
+//! [`DenseVisited`] when the states are numbered. The [`Stats`] of each search tell which
+//! choice does the least work. Unlike a [`Search`] built by hand, the drivers keep the path to
+//! the goal.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// A state of a puzzle, described by the moves leaving it.
+pub trait SearchState: Sized {
+    /// What identifies the state once reached, such as its position without the moves made
+    /// to get there.
+    type Key: Eq + Hash;
+
+    fn key(&self) -> Self::Key;
+
+    /// Pushes the states one move away, each with the cost of the move.
+    fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>);
+
+    fn is_goal(&self) -> bool;
+
+    /// A lower bound of the cost left to a goal, which never drops by more than the cost of a
+    /// move. Only [`a_star`] and [`ida_star`] use it.
+    fn heuristic(&self) -> usize {
+        return 0;
+    }
+}
+
+/// A goal reached by a search, with the way to it and the total cost of its moves.
+#[derive(Clone, Debug)]
+pub struct Found<S> {
+    /// The states from the start to the goal, both included, or only the goal when the search
+    /// did not [keep the path](Search::keep_path).
+    pub path: Vec<S>,
+    pub cost: usize,
+    pub stats: Stats,
+}
+
+impl<S> Found<S> {
+    pub fn goal(&self) -> &S {
+        return self.path.last().expect("A path holds at least its start");
+    }
+
+    pub fn into_goal(mut self) -> S {
+        return self.path.pop().expect("A path holds at least its start");
+    }
+
+    /// The number of moves to the goal, whatever their costs, when the path was kept.
+    pub fn moves(&self) -> usize {
+        return self.path.len() - 1;
+    }
+}
+
+/// The work done by a search, to compare the strategies on the same puzzle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The states whose neighbours were listed.
+    pub expanded: usize,
+    /// The most states waiting in the frontier at once, or on the path for [`ida_star`].
+    pub peak_frontier: usize,
+    /// The states dropped for being reached again, when listed as a neighbour or popped.
+    pub duplicates: usize,
+    pub elapsed: Duration,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(
+            f,
+            "{} expanded, {} at most in the frontier, {} duplicates pruned in {:?}",
+            self.expanded, self.peak_frontier, self.duplicates, self.elapsed,
+        );
+    }
+}
+
+/// A state waiting in the frontier of a [`Search`], with the way it was reached.
+pub struct Node<S> {
+    state: S,
+    cost: usize,
+    parent: usize,
+}
+
+/// The parent of the start, which has none.
+const ROOT: usize = usize::MAX;
+
+/// The states waiting to be expanded, popped by increasing priority.
+pub trait Frontier<T> {
+    fn push(&mut self, priority: usize, item: T);
+    fn pop(&mut self) -> Option<T>;
+    fn len(&self) -> usize;
+
+    /// Drops every item, for the frontier to start another search.
+    fn clear(&mut self);
+
+    fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+
+/// Pops in the order of the pushes, ignoring the priorities: the frontier of [`bfs`].
+#[derive(Debug)]
+pub struct Queue<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Self {
+        return Queue { items: VecDeque::new() };
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        return Queue::new();
+    }
+}
+
+impl<T> Frontier<T> for Queue<T> {
+    fn push(&mut self, _priority: usize, item: T) {
+        self.items.push_back(item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        return self.items.pop_front();
+    }
+
+    fn len(&self) -> usize {
+        return self.items.len();
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+struct Prioritized<T> {
+    priority: usize,
+    item: T,
+}
+
+impl<T> Ord for Prioritized<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The standard heap pops its largest item first
+        return other.priority.cmp(&self.priority);
+    }
+}
+
+impl<T> PartialOrd for Prioritized<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<T> PartialEq for Prioritized<T> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.priority == other.priority;
+    }
+}
+
+impl<T> Eq for Prioritized<T> {}
+
+/// Pops the lowest priority first, whatever the order of the pushes.
+pub struct Heap<T> {
+    items: BinaryHeap<Prioritized<T>>,
+}
+
+impl<T> Heap<T> {
+    pub fn new() -> Self {
+        return Heap { items: BinaryHeap::new() };
+    }
+}
+
+impl<T> Default for Heap<T> {
+    fn default() -> Self {
+        return Heap::new();
+    }
+}
+
+impl<T> Frontier<T> for Heap<T> {
+    fn push(&mut self, priority: usize, item: T) {
+        self.items.push(Prioritized { priority, item });
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        return self.items.pop().map(|prioritized| prioritized.item);
+    }
+
+    fn len(&self) -> usize {
+        return self.items.len();
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+/// One list of items per priority, popped from the lowest one. Faster than a [`Heap`] when the
+/// priorities are small integers that never go below the last one popped, as in [`dijkstra`] and
+/// [`a_star`].
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    lowest: usize,
+    len: usize,
+}
+
+impl<T> BucketQueue<T> {
+    pub fn new() -> Self {
+        return BucketQueue { buckets: Vec::new(), lowest: 0, len: 0 };
+    }
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        return BucketQueue::new();
+    }
+}
+
+impl<T> Frontier<T> for BucketQueue<T> {
+    fn push(&mut self, priority: usize, item: T) {
+        assert!(priority >= self.lowest, "Priority {priority} is lower than the last one popped, {}", self.lowest);
+        if priority >= self.buckets.len() {
+            self.buckets.resize_with(priority + 1, Vec::new);
+        }
+        self.buckets[priority].push(item);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        while self.buckets[self.lowest].is_empty() {
+            self.lowest += 1;
+        }
+        self.len -= 1;
+        return self.buckets[self.lowest].pop();
+    }
+
+    fn len(&self) -> usize {
+        return self.len;
+    }
+
+    fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.lowest = 0;
+        self.len = 0;
+    }
+}
+
+/// The keys of the states already expanded.
+pub trait Visited<K> {
+    /// Marks the key as visited, returning whether it was not already.
+    fn visit(&mut self, key: K) -> bool;
+    fn is_visited(&self, key: &K) -> bool;
+    fn len(&self) -> usize;
+
+    /// Forgets every key, for the set to start another search.
+    fn clear(&mut self);
+
+    fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+
+impl<K: Eq + Hash> Visited<K> for HashSet<K> {
+    fn visit(&mut self, key: K) -> bool {
+        return self.insert(key);
+    }
+
+    fn is_visited(&self, key: &K) -> bool {
+        return self.contains(key);
+    }
+
+    fn len(&self) -> usize {
+        return HashSet::len(self);
+    }
+
+    fn clear(&mut self) {
+        HashSet::clear(self);
+    }
+}
+
+/// Visited flags for states numbered from zero, such as the offsets of the squares of a map.
+pub struct DenseVisited {
+    visited: Vec<bool>,
+    len: usize,
+}
+
+impl DenseVisited {
+    /// Room for the keys below `capacity`, growing for the larger ones.
+    pub fn new(capacity: usize) -> Self {
+        return DenseVisited { visited: vec![false; capacity], len: 0 };
+    }
+}
+
+impl Visited<usize> for DenseVisited {
+    fn visit(&mut self, key: usize) -> bool {
+        if key >= self.visited.len() {
+            self.visited.resize(key + 1, false);
+        }
+        if self.visited[key] {
+            return false;
+        }
+        self.visited[key] = true;
+        self.len += 1;
+        return true;
+    }
+
+    fn is_visited(&self, key: &usize) -> bool {
+        return self.visited.get(*key).copied().unwrap_or(false);
+    }
+
+    fn len(&self) -> usize {
+        return self.len;
+    }
+
+    fn clear(&mut self) {
+        self.visited.fill(false);
+        self.len = 0;
+    }
+}
+
+/// What the frontier is ordered by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// The cost so far, for Dijkstra's algorithm, or the order of discovery with a [`Queue`].
+    Cost,
+    /// The cost so far plus the [heuristic](SearchState::heuristic), for A*.
+    Estimate,
+}
+
+/// A best-first search, expanding the states of its frontier one at a time.
+///
+/// States are closed when popped rather than when pushed, so that a cheaper path found after
+/// the first one still wins. The same state may then sit in the frontier several times, only
+/// its cheapest copy being expanded.
+pub struct Search<F, V> {
+    order: Order,
+    frontier: F,
+    visited: V,
+    keep_path: bool,
+    stats: Stats,
+}
+
+impl<F, V> Search<F, V> {
+    pub fn new(order: Order, frontier: F, visited: V) -> Self {
+        return Search { order, frontier, visited, keep_path: false, stats: Stats::default() };
+    }
+
+    /// Whether to keep the expanded states with their parents until the end of the search, to
+    /// follow the path back from the goal. Off by default, as it slows down the searches through
+    /// small states.
+    pub fn keep_path(self, keep_path: bool) -> Self {
+        return Search { keep_path, ..self };
+    }
+
+    /// The states expanded by the last search, all those reachable once it fails.
+    pub fn visited(&self) -> &V {
+        return &self.visited;
+    }
+
+    /// The work done by the last search, whether it reached a goal or not.
+    pub fn stats(&self) -> Stats {
+        return self.stats;
+    }
+
+    /// The cheapest goal reachable from `start`, if any. Forgets what an earlier run left in the
+    /// frontier and the visited set.
+    #[inline]
+    pub fn run<S>(&mut self, start: S) -> Option<Found<S>>
+    where
+        S: SearchState,
+        F: Frontier<Node<S>>,
+        V: Visited<S::Key>,
+    {
+        let started = Instant::now();
+        self.frontier.clear();
+        self.visited.clear();
+        let mut stats = Stats::default();
+        let mut expanded: Vec<(S, usize)> = Vec::new();
+        let mut neighbours = Vec::new();
+        let priority = self.priority(&start, 0);
+        self.frontier.push(priority, Node { state: start, cost: 0, parent: ROOT });
+        stats.peak_frontier = self.frontier.len();
+
+        while let Some(Node { state, cost, parent }) = self.frontier.pop() {
+            if !self.visited.visit(state.key()) {
+                stats.duplicates += 1;
+                continue;
+            }
+            if state.is_goal() {
+                stats.elapsed = started.elapsed();
+                let path = match self.keep_path {
+                    true => trace_back(expanded, state, parent),
+                    false => vec![state],
+                };
+                self.stats = stats;
+                return Some(Found { path, cost, stats });
+            }
+
+            state.neighbours(&mut neighbours);
+            for (neighbour, step) in neighbours.drain(..) {
+                if self.visited.is_visited(&neighbour.key()) {
+                    stats.duplicates += 1;
+                    continue;
+                }
+                let priority = self.priority(&neighbour, cost + step);
+                self.frontier.push(priority, Node { state: neighbour, cost: cost + step, parent: stats.expanded });
+            }
+            stats.expanded += 1;
+            stats.peak_frontier = stats.peak_frontier.max(self.frontier.len());
+            if self.keep_path {
+                expanded.push((state, parent));
+            }
+        }
+
+        stats.elapsed = started.elapsed();
+        self.stats = stats;
+        return None;
+    }
+
+    #[inline]
+    fn priority<S: SearchState>(&self, state: &S, cost: usize) -> usize {
+        return match self.order {
+            Order::Cost => cost,
+            Order::Estimate => cost + state.heuristic(),
+        };
+    }
+}
+
+/// The path to `goal`, given the index of its parent among the `expanded` states.
+fn trace_back<S>(expanded: Vec<(S, usize)>, goal: S, mut parent: usize) -> Vec<S> {
+    let mut on_path = Vec::new();
+    while parent != ROOT {
+        on_path.push(parent);
+        parent = expanded[parent].1;
+    }
+
+    // Parents are expanded before their children, so the path is in increasing order
+    on_path.reverse();
+    let mut path: Vec<S> = expanded
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| on_path.binary_search(index).is_ok())
+        .map(|(_, (state, _))| state)
+        .collect();
+    path.push(goal);
+    return path;
+}
+
+/// The goal reached in the fewest moves, whatever their costs.
+pub fn bfs<S: SearchState>(start: S) -> Option<Found<S>> {
+    return Search::new(Order::Cost, Queue::new(), HashSet::new()).keep_path(true).run(start);
+}
+
+/// The cheapest goal.
+pub fn dijkstra<S: SearchState>(start: S) -> Option<Found<S>> {
+    return Search::new(Order::Cost, Heap::new(), HashSet::new()).keep_path(true).run(start);
+}
+
+/// The cheapest goal, expanding first the states closest to one according to the heuristic.
+pub fn a_star<S: SearchState>(start: S) -> Option<Found<S>> {
+    return Search::new(Order::Estimate, Heap::new(), HashSet::new()).keep_path(true).run(start);
+}
+
+/// The cheapest goal, with depth-first searches bounded by an estimate growing until one
+/// reaches it. Remembers only the states on the current path, at the price of expanding the
+/// others again on every round.
+pub fn ida_star<S: SearchState>(start: S) -> Option<Found<S>> {
+    let started = Instant::now();
+    let mut stats = Stats::default();
+    let mut bound = start.heuristic();
+    let mut path = vec![start.key()];
+    let mut start = Some(start);
+    loop {
+        match deepen(start.take().unwrap(), 0, bound, &mut path, &mut stats) {
+            Deepening::Found(mut found) => {
+                // The states were added while returning from the goal
+                found.path.reverse();
+                found.stats = Stats { elapsed: started.elapsed(), ..stats };
+                return Some(found);
+            }
+            Deepening::Exceeded(state, estimate) => {
+                start = Some(state);
+                bound = estimate;
+            }
+            Deepening::Exhausted(_) => return None,
+        }
+    }
+}
+
+enum Deepening<S> {
+    Found(Found<S>),
+    /// The lowest estimate above the bound, to try next, handing the state back.
+    Exceeded(S, usize),
+    Exhausted(S),
+}
+
+fn deepen<S: SearchState>(state: S, cost: usize, bound: usize, path: &mut Vec<S::Key>, stats: &mut Stats) -> Deepening<S> {
+    let estimate = cost + state.heuristic();
+    if estimate > bound {
+        return Deepening::Exceeded(state, estimate);
+    }
+    if state.is_goal() {
+        return Deepening::Found(Found { path: vec![state], cost, stats: Stats::default() });
+    }
+
+    let mut neighbours = Vec::new();
+    state.neighbours(&mut neighbours);
+    stats.expanded += 1;
+    stats.peak_frontier = stats.peak_frontier.max(path.len());
+    let mut lowest_exceeding = None;
+    for (neighbour, step) in neighbours {
+        let key = neighbour.key();
+        if path.contains(&key) {
+            stats.duplicates += 1;
+            continue;
+        }
+
+        path.push(key);
+        let deepening = deepen(neighbour, cost + step, bound, path, stats);
+        path.pop();
+        match deepening {
+            Deepening::Found(mut found) => {
+                found.path.push(state);
+                return Deepening::Found(found);
+            }
+            Deepening::Exceeded(_, estimate) => {
+                lowest_exceeding = Some(lowest_exceeding.unwrap_or(usize::MAX).min(estimate));
+            }
+            Deepening::Exhausted(_) => {}
+        }
+    }
+
+    return match lowest_exceeding {
+        Some(estimate) => Deepening::Exceeded(state, estimate),
+        None => Deepening::Exhausted(state),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use crate::direction::Direction;
+    use crate::grid::Grid;
+    use crate::vector::Vector2D;
+
+    const MAZE: &[u8] = b"\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E
+";
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Walk<'a> {
+        maze: &'a Grid<u8>,
+        position: Vector2D<usize>,
+        down_cost: usize,
+    }
+
+    impl SearchState for Walk<'_> {
+        type Key = Vector2D<usize>;
+
+        fn key(&self) -> Self::Key {
+            return self.position;
+        }
+
+        fn neighbours(&self, neighbours: &mut Vec<(Self, usize)>) {
+            for position in self.maze.neighbours4(self.position) {
+                if self.maze[position] != b'#' {
+                    let cost = match self.maze.neighbour(self.position, Direction::Down) == Some(position) {
+                        true => self.down_cost,
+                        false => 1,
+                    };
+                    neighbours.push((Walk { position, ..*self }, cost));
+                }
+            }
+        }
+
+        fn is_goal(&self) -> bool {
+            return self.maze[self.position] == b'E';
+        }
+
+        fn heuristic(&self) -> usize {
+            let goal = self.maze.find(|square| *square == b'E').unwrap();
+            return goal.x.abs_diff(self.position.x) + goal.y.abs_diff(self.position.y);
+        }
+    }
+
+    fn start(maze: &Grid<u8>, down_cost: usize) -> Walk<'_> {
+        return Walk { maze, position: maze.find(|square| *square == b'S').unwrap(), down_cost };
+    }
+
+    /// Checks that the path walks from the start to the goal one square at a time, for the cost
+    /// found.
+    fn assert_walks(found: &Found<Walk>) {
+        let path = &found.path;
+        assert_eq!(path[0].maze[path[0].position], b'S');
+        assert!(found.goal().is_goal());
+
+        let mut cost = 0;
+        for pair in path.windows(2) {
+            let mut neighbours = Vec::new();
+            pair[0].neighbours(&mut neighbours);
+            let (_, step) = neighbours.into_iter().find(|(next, _)| next.position == pair[1].position).unwrap();
+            cost += step;
+        }
+        assert_eq!(cost, found.cost);
+    }
+
+    #[test]
+    fn drivers_find_the_cheapest_goal() {
+        let maze = Grid::parse(MAZE, |square| square).unwrap();
+        let found = bfs(start(&maze, 1)).unwrap();
+        assert_walks(&found);
+        assert_eq!((found.cost, found.moves()), (15, 15));
+
+        // Walking down costs twice as much, the fewest moves are no longer the cheapest
+        for found in [dijkstra(start(&maze, 2)), a_star(start(&maze, 2)), ida_star(start(&maze, 2))] {
+            let found = found.unwrap();
+            assert_walks(&found);
+            assert_eq!((found.cost, found.goal().position), (21, Vector2D::new(7, 4)));
+        }
+
+        let mut search = Search::new(Order::Estimate, BucketQueue::new(), HashSet::new());
+        let found = search.run(start(&maze, 2)).unwrap();
+        assert_eq!((found.cost, found.path.len()), (21, 1));
+    }
+
+    #[test]
+    fn counts_the_work_done() {
+        let maze = Grid::parse(MAZE, |square| square).unwrap();
+        let dijkstra = dijkstra(start(&maze, 2)).unwrap().stats;
+        let a_star = a_star(start(&maze, 2)).unwrap().stats;
+        assert!(a_star.expanded <= dijkstra.expanded);
+
+        // The goal is visited without being expanded, and the squares behind it are not reached
+        let open = maze.iter().filter(|square| **square != b'#').count();
+        let mut search = Search::new(Order::Cost, Queue::new(), HashSet::new());
+        search.run(start(&maze, 1));
+        assert!(search.stats().expanded < open);
+        assert_eq!(search.stats().expanded, search.visited().len() - 1);
+        assert!(search.stats().peak_frontier > 0 && search.stats().duplicates > 0);
+    }
+
+    #[test]
+    fn fails_without_a_way_to_the_goal() {
+        let maze = Grid::parse(b"S#.\n##E\n", |square| square).unwrap();
+        let mut search = Search::new(Order::Cost, Queue::new(), HashSet::new());
+        assert!(search.run(start(&maze, 1)).is_none());
+        assert_eq!(search.visited().len(), 1);
+        assert_eq!((search.stats().expanded, search.stats().peak_frontier), (1, 1));
+        assert!(ida_star(start(&maze, 1)).is_none());
+    }
+
+    #[test]
+    fn runs_again_from_scratch() {
+        let maze = Grid::parse(MAZE, |square| square).unwrap();
+        let mut search = Search::new(Order::Cost, BucketQueue::new(), HashSet::new());
+        let first = search.run(start(&maze, 1)).unwrap();
+        let visited = search.visited().len();
+        assert_eq!(search.run(start(&maze, 2)).unwrap().cost, 21);
+        let again = search.run(start(&maze, 1)).unwrap();
+        assert_eq!((again.cost, search.visited().len()), (first.cost, visited));
+    }
+
+    #[test]
+    fn keeps_the_path_and_stats_of_the_last_run() {
+        let maze = Grid::parse(MAZE, |square| square).unwrap();
+        let mut search = Search::new(Order::Estimate, Heap::new(), HashSet::new()).keep_path(true);
+        let first = search.run(start(&maze, 2)).unwrap();
+        search.run(start(&maze, 1)).unwrap();
+        let again = search.run(start(&maze, 2)).unwrap();
+        assert_walks(&again);
+
+        let positions = |found: &Found<Walk>| found.path.iter().map(|walk| walk.position).collect::<Vec<_>>();
+        let work = |stats: Stats| Stats { elapsed: Duration::ZERO, ..stats };
+        assert_eq!(positions(&again), positions(&first));
+        assert_eq!(work(again.stats), work(first.stats));
+        assert_eq!(work(search.stats()), work(first.stats));
+    }
+
+    proptest! {
+        #[test]
+        fn visited_sets_agree(keys in prop::collection::vec(0..256usize, 0..512)) {
+            let mut hash_set = HashSet::new();
+            let mut dense = DenseVisited::new(64);
+            for key in keys {
+                prop_assert_eq!(dense.visit(key), hash_set.visit(key));
+                prop_assert!(dense.is_visited(&key));
+                prop_assert_eq!(Visited::len(&dense), Visited::len(&hash_set));
+            }
+        }
+    }
+}
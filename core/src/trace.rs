@@ -0,0 +1,26 @@
+//! An optional channel for the progress and debug output of the solvers.
+//!
+//! Tracing is off by default so that solving stays quiet and fast to time; the runner turns
+//! it on with `--trace`. Traced lines go to stderr and never mix with the answers.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    return ENABLED.load(Ordering::Relaxed);
+}
+
+/// Prints a line to stderr when tracing is enabled, taking the same arguments as `eprintln!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,168 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, Div, Neg};
+use num_traits::Signed;
+
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Vector2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector2D<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        return Vector2D { x, y };
+    }
+
+    /// The same vector in a wider type, such as `i32` components from `i16` ones.
+    pub fn cast<U: From<T>>(self) -> Vector2D<U> {
+        return Vector2D {
+            x: U::from(self.x),
+            y: U::from(self.y),
+        };
+    }
+
+    /// The same vector in a narrower type, failing when a component does not fit.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Result<Vector2D<U>, U::Error> {
+        return Ok(Vector2D {
+            x: U::try_from(self.x)?,
+            y: U::try_from(self.y)?,
+        });
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign<Vector2D<T>> for Vector2D<T> {
+    fn add_assign(&mut self, rhs: Vector2D<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign<T>> SubAssign<Vector2D<T>> for Vector2D<T> {
+    fn sub_assign(&mut self, rhs: Vector2D<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<T, Output = T>> Add<Vector2D<T>> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn add(self, rhs: Vector2D<T>) -> Self::Output {
+        return Vector2D {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        };
+    }
+}
+
+impl<T: Sub<T, Output = T>> Sub<Vector2D<T>> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn sub(self, rhs: Vector2D<T>) -> Self::Output {
+        return Vector2D {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        };
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn neg(self) -> Self::Output {
+        return Vector2D {
+            x: -self.x,
+            y: -self.y,
+        };
+    }
+}
+
+impl<T: Mul<T, Output = T> + Copy> Mul<T> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        return Vector2D {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        };
+    }
+}
+
+impl<T: Div<T, Output = T> + Copy> Div<T> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        return Vector2D {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        };
+    }
+}
+
+impl<T: Ord> Vector2D<T> {
+    /// The smallest of each component, the corner of the bounding box of both vectors.
+    pub fn min(self, other: Self) -> Self {
+        return Vector2D {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        };
+    }
+
+    /// The largest of each component, the corner of the bounding box of both vectors.
+    pub fn max(self, other: Self) -> Self {
+        return Vector2D {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        };
+    }
+}
+
+impl<T: Signed> Vector2D<T> {
+    /// The sign of each component, a step of at most one square towards the vector, diagonals
+    /// included.
+    pub fn signum(&self) -> Self {
+        return Vector2D {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        };
+    }
+
+    /// The distance walked along the axes.
+    pub fn manhattan_length(self) -> T {
+        return self.x.abs() + self.y.abs();
+    }
+
+    /// The distance walked when diagonal steps count as one, like the moves of a king.
+    pub fn chebyshev_length(self) -> T where T: Ord {
+        return self.x.abs().max(self.y.abs());
+    }
+}
+
+impl<T: Display> Display for Vector2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "({}, {})", self.x, self.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_distances() {
+        let vector = Vector2D::new(-3, 2);
+        assert_eq!((vector.manhattan_length(), vector.chebyshev_length()), (5, 3));
+        assert_eq!(vector.signum(), Vector2D::new(-1, 1));
+        assert_eq!(-vector * 2, Vector2D::new(6, -4));
+        assert_eq!(vector.min(Vector2D::new(0, 5)), Vector2D::new(-3, 2));
+        assert_eq!(vector.max(Vector2D::new(0, 5)), Vector2D::new(0, 5));
+    }
+
+    #[test]
+    fn converts_between_widths() {
+        let small: Vector2D<i16> = Vector2D::new(-3, 300);
+        assert_eq!(small.cast::<i64>(), Vector2D::new(-3i64, 300));
+        assert!(small.try_cast::<u8>().is_err());
+        assert_eq!(Vector2D::new(3i64, 200).try_cast::<u8>(), Ok(Vector2D::new(3u8, 200)));
+    }
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+use crate::answer::{Answer, SolveError};
+
+/// A named variant of a solver, taking the raw puzzle input.
+pub type Solution<'a> = (&'a str, &'a dyn Fn(&[u8]) -> String);
+
+/// The variants registered for both parts of a day, the main one first.
+pub struct Solutions<'a> {
+    pub part2: Vec<Solution<'a>>,
+    pub part1: Vec<Solution<'a>>,
+}
+
+/// A year of puzzles, implemented by every year crate to plug into the runner.
+pub trait Year: Sync {
+    fn year(&self) -> usize;
+
+    /// The variants registered for a day, `None` when the day has not been solved.
+    fn solutions(&self, day: usize) -> Option<Solutions<'static>>;
+
+    /// The crate directory, holding the checked-in inputs and the benchmark baselines saved on
+    /// this machine, which are not checked in.
+    fn directory(&self) -> PathBuf;
+
+    fn input_directory(&self) -> PathBuf {
+        return self.directory().join("input");
+    }
+
+    /// File name of the input of a day inside an input directory.
+    fn input_name(&self, day: usize) -> String {
+        return format!("{day:02}");
+    }
+
+    fn answers_path(&self) -> PathBuf {
+        return self.input_directory().join("answers.toml");
+    }
+
+    /// The worked examples of the puzzle statements, see [`crate::fixtures`].
+    fn fixture_directory(&self) -> PathBuf {
+        return self.directory().join("fixtures");
+    }
+
+    fn baseline_directory(&self) -> PathBuf {
+        return self.directory().join("baselines");
+    }
+
+    /// The variants registered for a part of a day, the main one first.
+    fn variants(&self, day: usize, part: usize) -> Result<Vec<Solution<'static>>, SolveError> {
+        let solutions = self.solutions(day).ok_or(SolveError::UnknownDay(day))?;
+        return match part {
+            1 => Ok(solutions.part1),
+            2 => Ok(solutions.part2),
+            _ => Err(SolveError::UnknownPart(part)),
+        };
+    }
+
+    /// Solves a part of a day with its main variant.
+    ///
+    /// # Panics
+    ///
+    /// The solvers trust their input to be a well-formed puzzle input and may panic otherwise.
+    fn run(&self, day: usize, part: usize, input: &[u8]) -> Result<Answer, SolveError> {
+        let (_, solution) = self.variants(day, part)?[0];
+        return Ok(Answer::from(solution(input)));
+    }
+
+    /// Solves a part of a day with the variant registered under `variant`.
+    ///
+    /// # Panics
+    ///
+    /// The solvers trust their input to be a well-formed puzzle input and may panic otherwise.
+    fn run_variant(&self, day: usize, part: usize, variant: &str, input: &[u8]) -> Result<Answer, SolveError> {
+        let (_, solution) = self.variants(day, part)?
+            .into_iter()
+            .find(|(name, _)| *name == variant)
+            .ok_or_else(|| SolveError::UnknownVariant { day, part, variant: variant.to_string() })?;
+        return Ok(Answer::from(solution(input)));
+    }
+}
@@ -0,0 +1,34 @@
+#![no_main]
+
+use aoc_core::parse;
+use aoc_core::parser::{Cursor, Expected};
+use libfuzzer_sys::fuzz_target;
+
+// Every primitive starts from every offset of the buffer, the way the solutions call them mid-input
+fuzz_target!(|buffer: &[u8]| {
+    for start in 0..=buffer.len() {
+        let cursor = || {
+            let mut cursor = Cursor::new(buffer);
+            cursor.skip(start);
+            return cursor;
+        };
+
+        let _ = cursor().read_unsigned_int();
+        let _ = cursor().read_signed_int();
+        let _ = cursor().read_int::<u8>();
+        let _ = cursor().read_int::<i16>();
+        let _ = cursor().read_int::<i128>();
+        let _ = cursor().read_word();
+        let _ = cursor().read_identifier();
+        let _ = cursor().expect_literal(b"move ");
+        let _ = cursor().expect(b'\n');
+        cursor().skip_word();
+        cursor().move_to_next_line();
+        let _ = cursor().error(Expected::Word);
+
+        let mut valve = cursor();
+        if let Ok((_, _, tunnels)) = parse!(&mut valve, "Valve " {id} " has flow rate=" {u32} "; tunnel" {s?} " lead" {s?} " to valve" {s?} " " {list:id} "\n") {
+            tunnels.iter().for_each(drop);
+        }
+    }
+});
@@ -0,0 +1,12 @@
+//! Shared by the fuzz targets, one per day of 2022 and one for the parser primitives.
+
+use aoc_core::Year;
+use aoc2022::Aoc2022;
+
+/// Feeds the input to every variant of both parts of a day, any panic is a finding.
+pub fn solve(day: usize, input: &[u8]) {
+    let solutions = Aoc2022.solutions(day).unwrap();
+    for (_, solution) in solutions.part1.iter().chain(&solutions.part2) {
+        solution(input);
+    }
+}